@@ -0,0 +1,614 @@
+//! A synchronous wrapper around [SzurubooruClient] for callers that don't run an async runtime.
+//!
+//! Every request is driven to completion on a current-thread Tokio runtime owned by the
+//! [SzurubooruBlockingClient], so the same [models](crate::models) and
+//! [tokens](crate::tokens) are used as with the async client and only the call site differs.
+//!
+//! ```no_run
+//! use szurubooru_client::blocking::SzurubooruBlockingClient;
+//! use szurubooru_client::tokens::*;
+//! let client = SzurubooruBlockingClient::new_with_token("http://localhost:5001", "myuser",
+//!     "sz-123456", true).unwrap();
+//! let query = vec![QueryToken::token(PostNamedToken::Tag, "tagme")];
+//! let posts = client.with_limit(10).list_posts(Some(&query)).unwrap();
+//! ```
+//!
+//! Streaming methods such as [list_all_posts](SzurubooruRequest::list_all_posts) have no
+//! blocking counterpart. Use the `collect_all_*` methods, like
+//! [collect_all_posts](SzurubooruBlockingRequest::collect_all_posts), or
+//! [block_on](SzurubooruBlockingClient::block_on) with the async request from
+//! [as_async](SzurubooruBlockingRequest::as_async) to drive them instead.
+
+use crate::errors::{SzurubooruClientError, SzurubooruResult};
+use crate::models::*;
+use crate::tokens::QueryToken;
+use crate::{SzurubooruClient, SzurubooruRequest};
+use std::fmt::Display;
+#[cfg(feature = "fs")]
+use std::fs::File;
+use std::future::Future;
+#[cfg(feature = "fs")]
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::io::AsyncBufRead;
+use tokio::runtime::{Builder, Runtime};
+
+/// Synchronous counterpart of [SzurubooruClient]. Requests made through it block the calling
+/// thread until the server responds.
+///
+/// It must not be used from within an async context, as driving the runtime from there panics.
+#[derive(Debug)]
+pub struct SzurubooruBlockingClient {
+    client: SzurubooruClient,
+    runtime: Runtime,
+}
+
+impl SzurubooruBlockingClient {
+    /// Wrap an existing [SzurubooruClient], such as one made with
+    /// [SzurubooruClient::builder]
+    ///
+    /// May return a [SzurubooruClientError::IOError] if the runtime can't be started
+    pub fn from_client(client: SzurubooruClient) -> SzurubooruResult<Self> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(SzurubooruClientError::IOError)?;
+        Ok(Self { client, runtime })
+    }
+
+    /// Blocking version of [SzurubooruClient::new_with_token]
+    pub fn new_with_token(
+        host: &str,
+        username: &str,
+        token: &str,
+        allow_insecure: bool,
+    ) -> SzurubooruResult<Self> {
+        Self::from_client(SzurubooruClient::new_with_token(
+            host,
+            username,
+            token,
+            allow_insecure,
+        )?)
+    }
+
+    /// Blocking version of [SzurubooruClient::new_with_basic_auth]
+    pub fn new_with_basic_auth(
+        host: &str,
+        username: &str,
+        password: &str,
+        allow_insecure: bool,
+    ) -> SzurubooruResult<Self> {
+        Self::from_client(SzurubooruClient::new_with_basic_auth(
+            host,
+            username,
+            password,
+            allow_insecure,
+        )?)
+    }
+
+    /// Blocking version of [SzurubooruClient::new_anonymous]
+    pub fn new_anonymous(host: &str, allow_insecure: bool) -> SzurubooruResult<Self> {
+        Self::from_client(SzurubooruClient::new_anonymous(host, allow_insecure)?)
+    }
+
+    /// The wrapped async client
+    pub fn client(&self) -> &SzurubooruClient {
+        &self.client
+    }
+
+    /// Drive any future to completion on this client's runtime. Useful for the async-only
+    /// methods, like the `list_all_*` streams
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Blocking version of [SzurubooruClient::request]
+    pub fn request(&self) -> SzurubooruBlockingRequest<'_> {
+        SzurubooruBlockingRequest {
+            request: self.client.request(),
+            runtime: &self.runtime,
+        }
+    }
+
+    /// Blocking version of [SzurubooruClient::with_fields]
+    pub fn with_fields(&self, fields: Vec<String>) -> SzurubooruBlockingRequest<'_> {
+        self.request().with_fields(fields)
+    }
+
+    /// Blocking version of [SzurubooruClient::with_limit]
+    pub fn with_limit(&self, limit: u32) -> SzurubooruBlockingRequest<'_> {
+        self.request().with_limit(limit)
+    }
+
+    /// Blocking version of [SzurubooruClient::with_offset]
+    pub fn with_offset(&self, offset: u32) -> SzurubooruBlockingRequest<'_> {
+        self.request().with_offset(offset)
+    }
+}
+
+/// Synchronous counterpart of [SzurubooruRequest], made with
+/// [SzurubooruBlockingClient::request]
+#[derive(Debug, Clone)]
+pub struct SzurubooruBlockingRequest<'a> {
+    request: SzurubooruRequest<'a>,
+    runtime: &'a Runtime,
+}
+
+/// Mirror [SzurubooruRequest]'s builder methods, rewrapping the request they return
+macro_rules! builder_methods {
+    ($($(#[$attr:meta])* fn $name:ident(self $(, $arg:ident: $ty:ty)*);)*) => {
+        $(
+            $(#[$attr])*
+            #[doc = concat!("See [SzurubooruRequest::", stringify!($name), "]")]
+            pub fn $name(self $(, $arg: $ty)*) -> Self {
+                Self {
+                    request: self.request.$name($($arg),*),
+                    runtime: self.runtime,
+                }
+            }
+        )*
+    };
+}
+
+/// Mirror [SzurubooruRequest]'s async methods by blocking on them
+macro_rules! blocking_methods {
+    ($($(#[$attr:meta])* fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;)*) => {
+        $(
+            $(#[$attr])*
+            #[doc = concat!("Blocking version of [SzurubooruRequest::", stringify!($name), "]")]
+            pub fn $name(&self $(, $arg: $ty)*) -> $ret {
+                self.runtime.block_on(self.request.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+impl<'a> SzurubooruBlockingRequest<'a> {
+    /// The async request this wraps, for the methods with no blocking counterpart
+    pub fn as_async(&self) -> &SzurubooruRequest<'a> {
+        &self.request
+    }
+
+    /// See [SzurubooruRequest::next_page]
+    pub fn next_page<T>(&self, previous: &PagedSearchResult<T>) -> Option<Self> {
+        self.request.next_page(previous).map(|request| Self {
+            request,
+            runtime: self.runtime,
+        })
+    }
+
+    builder_methods! {
+        fn with_fields(self, fields: Vec<String>);
+        fn with_optional_fields(self, val: Option<Vec<String>>);
+        fn with_post_fields(self, fields: &[PostField]);
+        fn with_tag_fields(self, fields: &[TagField]);
+        fn with_pool_fields(self, fields: &[PoolField]);
+        fn with_user_fields(self, fields: &[UserField]);
+        fn with_comment_fields(self, fields: &[CommentField]);
+        fn with_limit(self, limit: u32);
+        fn with_optional_limit(self, val: Option<u32>);
+        fn with_offset(self, offset: u32);
+        fn with_optional_offset(self, val: Option<u32>);
+        fn with_expanded_relations(self);
+        fn without_url_propagation(self);
+        fn with_retry(self, max_attempts: u32, base_delay: Duration);
+        fn with_non_idempotent_retry(self);
+        fn with_timeout(self, timeout: Duration);
+    }
+
+    blocking_methods! {
+        fn get_raw(&self, path: &str, query: Option<&Vec<QueryToken>>)
+            -> SzurubooruResult<serde_json::Value>;
+        fn list_tag_categories(&self)
+            -> SzurubooruResult<UnpagedSearchResult<TagCategoryResource>>;
+        fn tag_category_facets(&self) -> SzurubooruResult<Vec<(TagCategoryResource, u64)>>;
+        fn create_tag_category(&self, new_cat: &CreateUpdateTagCategory)
+            -> SzurubooruResult<TagCategoryResource>;
+        fn update_tag_category(
+            &self,
+            name: impl AsRef<str> + Display,
+            update_tag_cat: &CreateUpdateTagCategory
+        ) -> SzurubooruResult<TagCategoryResource>;
+        fn get_tag_category(&self, name: impl AsRef<str> + Display)
+            -> SzurubooruResult<TagCategoryResource>;
+        fn delete_tag_category(&self, name: impl AsRef<str> + Display, version: u32)
+            -> SzurubooruResult<()>;
+        fn set_default_tag_category(&self, name: impl AsRef<str> + Display)
+            -> SzurubooruResult<()>;
+
+        fn list_tags(&self, query: Option<&Vec<QueryToken>>)
+            -> SzurubooruResult<PagedSearchResult<TagResource>>;
+        fn collect_all_tags(&self, query: Option<&Vec<QueryToken>>, max_results: usize)
+            -> SzurubooruResult<Vec<TagResource>>;
+        fn suggest_tags(&self, prefix: &str, limit: u32) -> SzurubooruResult<Vec<TagResource>>;
+        fn count_tags(&self, query: Option<&Vec<QueryToken>>) -> SzurubooruResult<u64>;
+        fn create_tag(&self, new_tag: &CreateUpdateTag) -> SzurubooruResult<TagResource>;
+        fn create_tags(&self, tags: &[CreateUpdateTag]) -> Vec<SzurubooruResult<TagResource>>;
+        fn create_tags_with_concurrency(&self, tags: &[CreateUpdateTag], concurrency: usize)
+            -> Vec<SzurubooruResult<TagResource>>;
+        fn update_tag(&self, name: impl AsRef<str> + Display, update_tag: &CreateUpdateTag)
+            -> SzurubooruResult<TagResource>;
+        fn get_tag(&self, name: impl AsRef<str> + Display) -> SzurubooruResult<TagResource>;
+        fn get_tags_by_names(&self, names: &[String]) -> SzurubooruResult<Vec<TagResource>>;
+        fn expand_implications(&self, tags: &[String]) -> SzurubooruResult<Vec<String>>;
+        fn delete_tag(&self, name: impl AsRef<str> + Display, version: u32)
+            -> SzurubooruResult<()>;
+        fn merge_tags(&self, merge_opts: &MergeTags) -> SzurubooruResult<TagResource>;
+        fn merge_tags_by_name(
+            &self,
+            from: impl AsRef<str> + Display,
+            to: impl AsRef<str> + Display
+        ) -> SzurubooruResult<TagResource>;
+        fn merge_tag_resources(&self, remove: &TagResource, merge_to: &TagResource)
+            -> SzurubooruResult<TagResource>;
+        fn get_tag_siblings(&self, name: impl AsRef<str> + Display)
+            -> SzurubooruResult<UnpagedSearchResult<TagSibling>>;
+
+        fn list_posts(&self, query: Option<&Vec<QueryToken>>)
+            -> SzurubooruResult<PagedSearchResult<PostResource>>;
+        fn collect_all_posts(&self, query: Option<&Vec<QueryToken>>, max_results: usize)
+            -> SzurubooruResult<Vec<PostResource>>;
+        fn group_posts_by_category(&self, query: Option<&Vec<QueryToken>>, category: &str)
+            -> SzurubooruResult<std::collections::HashMap<String, Vec<PostResource>>>;
+        fn suggest_relations(&self, post_id: u32, min_shared_tags: usize, max_candidates: usize)
+            -> SzurubooruResult<Vec<u32>>;
+        fn page_posts(&self, query: Option<&Vec<QueryToken>>, page_number: u32)
+            -> SzurubooruResult<PagedSearchResult<PostResource>>;
+        fn last_page_posts(&self, query: Option<&Vec<QueryToken>>)
+            -> SzurubooruResult<PagedSearchResult<PostResource>>;
+        fn posts_created_after(&self, post_id: u32, max_results: usize)
+            -> SzurubooruResult<Vec<PostResource>>;
+        fn count_posts(&self, query: Option<&Vec<QueryToken>>) -> SzurubooruResult<u64>;
+        fn list_user_uploads(&self, username: impl AsRef<str>)
+            -> SzurubooruResult<PagedSearchResult<PostResource>>;
+        fn count_user_uploads(&self, username: impl AsRef<str>) -> SzurubooruResult<u64>;
+        fn list_favorites(&self, username: &str)
+            -> SzurubooruResult<PagedSearchResult<PostResource>>;
+        fn list_untagged_posts(&self) -> SzurubooruResult<PagedSearchResult<PostResource>>;
+        fn list_posts_missing_category(&self, category: &str)
+            -> SzurubooruResult<PagedSearchResult<PostResource>>;
+        fn create_post_from_url(&self, new_post: &CreateUpdatePost)
+            -> SzurubooruResult<PostResource>;
+        fn update_post(&self, post_id: u32, update_post: &CreateUpdatePost)
+            -> SzurubooruResult<PostResource>;
+        fn patch_post(&self, post_id: u32, version: u32, patch: &PostPatch)
+            -> SzurubooruResult<PostResource>;
+        fn update_post_retry_conflict(&self, post_id: u32, update_post: &CreateUpdatePost)
+            -> SzurubooruResult<PostResource>;
+        fn update_post_from_url(&self, post_id: u32, update_post: &CreateUpdatePost)
+            -> SzurubooruResult<PostResource>;
+        #[cfg(feature = "fs")]
+        fn create_post_from_file(
+            &self,
+            file: &mut File,
+            thumbnail: Option<&mut File>,
+            file_name: impl AsRef<str>,
+            new_post: &CreateUpdatePost
+        ) -> SzurubooruResult<PostResource>;
+        fn create_post_from_bytes(
+            &self,
+            bytes: impl Into<Vec<u8>>,
+            file_name: impl AsRef<str>,
+            new_post: &CreateUpdatePost
+        ) -> SzurubooruResult<PostResource>;
+        fn create_post_from_bytes_with_mime(
+            &self,
+            bytes: impl Into<Vec<u8>>,
+            mime: &str,
+            file_name: impl AsRef<str>,
+            new_post: &CreateUpdatePost
+        ) -> SzurubooruResult<PostResource>;
+        #[cfg(feature = "fs")]
+        fn create_post_from_file_path(
+            &self,
+            file_path: impl AsRef<Path>,
+            thumbnail: Option<impl AsRef<Path>>,
+            new_post: &CreateUpdatePost
+        ) -> SzurubooruResult<PostResource>;
+        #[cfg(feature = "fs")]
+        fn create_post_from_file_path_streaming(
+            &self,
+            file_path: impl AsRef<Path>,
+            thumbnail: Option<impl AsRef<Path>>,
+            new_post: &CreateUpdatePost
+        ) -> SzurubooruResult<PostResource>;
+        #[cfg(feature = "fs")]
+        fn create_post_from_file_path_with(
+            &self,
+            file_path: impl AsRef<Path>,
+            base_post: &CreateUpdatePost,
+            prepare: impl FnOnce(&Path, CreateUpdatePost) -> CreateUpdatePost
+        ) -> SzurubooruResult<PostResource>;
+        fn import_posts_jsonl(&self, reader: impl AsyncBufRead + Unpin, concurrency: usize)
+            -> Vec<PostImportResult>;
+        fn create_post_from_token(&self, new_post: &CreateUpdatePost)
+            -> SzurubooruResult<PostResource>;
+        #[cfg(feature = "fs")]
+        fn update_post_from_file(
+            &self,
+            post_id: u32,
+            file: Option<&mut File>,
+            thumbnail: Option<&mut File>,
+            file_name: impl AsRef<str>,
+            update_post: &CreateUpdatePost
+        ) -> SzurubooruResult<PostResource>;
+        #[cfg(feature = "fs")]
+        fn update_post_from_file_path(
+            &self,
+            post_id: u32,
+            file_path: Option<impl AsRef<Path>>,
+            thumbnail: Option<impl AsRef<Path>>,
+            update_post: &CreateUpdatePost
+        ) -> SzurubooruResult<PostResource>;
+        fn set_post_thumbnail_bytes(
+            &self,
+            post_id: u32,
+            version: u32,
+            thumbnail_bytes: impl Into<Vec<u8>>,
+            file_name: impl AsRef<str>
+        ) -> SzurubooruResult<PostResource>;
+        fn update_post_from_token(&self, post_id: u32, update_post: &CreateUpdatePost)
+            -> SzurubooruResult<PostResource>;
+        fn get_image_bytes(&self, post_id: u32) -> SzurubooruResult<bytes::Bytes>;
+        fn get_image_raw_bytes(&self, post_id: u32) -> SzurubooruResult<bytes::Bytes>;
+        fn get_thumbnail_bytes(&self, post_id: u32) -> SzurubooruResult<bytes::Bytes>;
+        #[cfg(feature = "fs")]
+        fn download_image_to_file(&self, post_id: u32, file: &mut File) -> SzurubooruResult<()>;
+        #[cfg(feature = "fs")]
+        fn download_image_to_file_with_progress(
+            &self,
+            post_id: u32,
+            file: &mut File,
+            progress: impl FnMut(u64, Option<u64>)
+        ) -> SzurubooruResult<()>;
+        #[cfg(feature = "fs")]
+        fn download_post_content(&self, post: &PostResource, file: &mut File)
+            -> SzurubooruResult<()>;
+        #[cfg(feature = "fs")]
+        fn download_image_to_path(&self, post_id: u32, path: impl AsRef<Path>)
+            -> SzurubooruResult<()>;
+        #[cfg(feature = "fs")]
+        fn download_images_to_dir(
+            &self,
+            post_ids: &[u32],
+            dir: impl AsRef<Path>,
+            concurrency: usize
+        ) -> Vec<SzurubooruResult<PathBuf>>;
+        #[cfg(feature = "fs")]
+        fn download_thumbnail_to_file(&self, post_id: u32, file: &mut File)
+            -> SzurubooruResult<()>;
+        #[cfg(feature = "fs")]
+        fn download_thumbnail_to_path(&self, post_id: u32, path: impl AsRef<Path>)
+            -> SzurubooruResult<()>;
+        #[cfg(feature = "fs")]
+        fn reverse_search_file(&self, file: &mut File, file_path: impl AsRef<str>)
+            -> SzurubooruResult<ImageSearchResult>;
+        #[cfg(feature = "fs")]
+        fn reverse_search_file_path(&self, file_path: impl AsRef<Path>)
+            -> SzurubooruResult<ImageSearchResult>;
+        fn reverse_search_bytes(&self, bytes: Vec<u8>, file_name: impl AsRef<str>)
+            -> SzurubooruResult<ImageSearchResult>;
+        #[cfg(feature = "fs")]
+        fn post_for_file(&self, file: &mut File) -> SzurubooruResult<Option<PostResource>>;
+        fn post_for_bytes(&self, bytes: &[u8]) -> SzurubooruResult<Option<PostResource>>;
+        #[cfg(feature = "fs")]
+        fn post_for_file_path(&self, file_path: impl AsRef<Path>)
+            -> SzurubooruResult<Option<PostResource>>;
+        fn post_for_md5(&self, md5_hex: &str) -> SzurubooruResult<Option<PostResource>>;
+        fn scan_posts_for_md5(
+            &self,
+            query: Option<&Vec<QueryToken>>,
+            md5_hex: &str,
+            max_results: usize
+        ) -> SzurubooruResult<Option<PostResource>>;
+        fn get_post(&self, post_id: u32) -> SzurubooruResult<PostResource>;
+        fn build_relation_graph(&self, seed_post_id: u32, max_depth: u32)
+            -> SzurubooruResult<RelationGraph>;
+        fn get_post_interaction_state(&self, post_id: u32) -> SzurubooruResult<PostInteraction>;
+        fn get_post_checksum(&self, post_id: u32) -> SzurubooruResult<String>;
+        fn get_around_post(&self, post_id: u32) -> SzurubooruResult<AroundPostResult>;
+        fn get_around_post_with_query(&self, post_id: u32, query: Option<&Vec<QueryToken>>)
+            -> SzurubooruResult<AroundPostResult>;
+        fn get_post_neighbors(&self, post_id: u32)
+            -> SzurubooruResult<(Option<PostResource>, Option<PostResource>)>;
+        fn delete_post(&self, post_id: u32, version: u32) -> SzurubooruResult<()>;
+        fn delete_posts(&self, ids_and_versions: &[(u32, u32)], concurrency: usize)
+            -> Vec<SzurubooruResult<()>>;
+        fn merge_post(&self, merge_opts: &MergePost) -> SzurubooruResult<PostResource>;
+        fn merge_post_resources(
+            &self,
+            remove: &PostResource,
+            merge_to: &PostResource,
+            replace_content: bool
+        ) -> SzurubooruResult<PostResource>;
+        fn rate_post(&self, post_id: u32, score: i8) -> SzurubooruResult<PostResource>;
+        fn favorite_post(&self, post_id: u32) -> SzurubooruResult<PostResource>;
+        fn like_post(&self, post_id: u32) -> SzurubooruResult<PostResource>;
+        fn unfavorite_post(&self, post_id: u32) -> SzurubooruResult<PostResource>;
+        fn get_featured_post(&self) -> SzurubooruResult<Option<PostResource>>;
+        fn set_featured_post(&self, post_id: u32) -> SzurubooruResult<PostResource>;
+
+        fn list_pool_categories(&self)
+            -> SzurubooruResult<UnpagedSearchResult<PoolCategoryResource>>;
+        fn create_pool_category(&self, new_cat: &CreateUpdatePoolCategory)
+            -> SzurubooruResult<PoolCategoryResource>;
+        fn update_pool_category(
+            &self,
+            category_name: impl AsRef<str> + Display,
+            update_cat: &CreateUpdatePoolCategory
+        ) -> SzurubooruResult<PoolCategoryResource>;
+        fn get_pool_category(&self, category_name: impl AsRef<str> + Display)
+            -> SzurubooruResult<PoolCategoryResource>;
+        fn delete_pool_category(&self, category_name: impl AsRef<str> + Display, version: u32)
+            -> SzurubooruResult<()>;
+        fn set_default_pool_category(&self, category_name: impl AsRef<str> + Display)
+            -> SzurubooruResult<PoolCategoryResource>;
+        fn list_pools(&self, query: Option<&Vec<QueryToken>>)
+            -> SzurubooruResult<PagedSearchResult<PoolResource>>;
+        fn list_pools_containing_post(&self, post_id: u32)
+            -> SzurubooruResult<PagedSearchResult<PoolResource>>;
+        fn collect_all_pools(&self, query: Option<&Vec<QueryToken>>, max_results: usize)
+            -> SzurubooruResult<Vec<PoolResource>>;
+        fn count_pools(&self, query: Option<&Vec<QueryToken>>) -> SzurubooruResult<u64>;
+        fn create_pool(&self, create_update_pool: &CreateUpdatePool)
+            -> SzurubooruResult<PoolResource>;
+        fn update_pool(&self, pool_id: u32, create_update_pool: &CreateUpdatePool)
+            -> SzurubooruResult<PoolResource>;
+        fn reorder_pool_posts(&self, pool_id: u32, version: u32, new_order: Vec<u32>)
+            -> SzurubooruResult<PoolResource>;
+        fn add_posts_to_pool(&self, pool_id: u32, post_ids: &[u32])
+            -> SzurubooruResult<PoolResource>;
+        fn remove_posts_from_pool(&self, pool_id: u32, post_ids: &[u32])
+            -> SzurubooruResult<PoolResource>;
+        fn get_pool(&self, pool_id: u32) -> SzurubooruResult<PoolResource>;
+        fn delete_pool(&self, pool_id: u32, version: u32) -> SzurubooruResult<()>;
+        fn merge_pools(&self, merge_pool: &MergePool) -> SzurubooruResult<PoolResource>;
+        fn merge_pools_by_id(&self, from: u32, to: u32) -> SzurubooruResult<PoolResource>;
+        fn merge_pool_resources(&self, remove: &PoolResource, merge_to: &PoolResource)
+            -> SzurubooruResult<PoolResource>;
+
+        fn list_comments(&self, query: Option<&Vec<QueryToken>>)
+            -> SzurubooruResult<PagedSearchResult<CommentResource>>;
+        fn collect_all_comments(&self, query: Option<&Vec<QueryToken>>, max_results: usize)
+            -> SzurubooruResult<Vec<CommentResource>>;
+        fn count_comments(&self, query: Option<&Vec<QueryToken>>) -> SzurubooruResult<u64>;
+        fn get_comments_for_post(&self, post_id: u32)
+            -> SzurubooruResult<PagedSearchResult<CommentResource>>;
+        fn create_comment(&self, new_comment: &CreateUpdateComment)
+            -> SzurubooruResult<CommentResource>;
+        fn update_comment(&self, comment_id: u32, update_comment: &CreateUpdateComment)
+            -> SzurubooruResult<CommentResource>;
+        fn edit_comment(&self, comment_id: u32, new_text: impl AsRef<str>)
+            -> SzurubooruResult<CommentResource>;
+        fn get_comment(&self, comment_id: u32) -> SzurubooruResult<CommentResource>;
+        fn delete_comment(&self, comment_id: u32, version: u32) -> SzurubooruResult<()>;
+        fn rate_comment(&self, comment_id: u32, score: i8) -> SzurubooruResult<CommentResource>;
+
+        fn list_users(&self, query: Option<&Vec<QueryToken>>)
+            -> SzurubooruResult<PagedSearchResult<UserResource>>;
+        fn collect_all_users(&self, query: Option<&Vec<QueryToken>>, max_results: usize)
+            -> SzurubooruResult<Vec<UserResource>>;
+        fn count_users(&self, query: Option<&Vec<QueryToken>>) -> SzurubooruResult<u64>;
+        fn create_user(&self, new_user: &CreateUpdateUser) -> SzurubooruResult<UserResource>;
+        #[cfg(feature = "fs")]
+        fn create_user_with_avatar_file(
+            &self,
+            avatar: &mut File,
+            file_name: impl AsRef<str>,
+            new_user: &CreateUpdateUser
+        ) -> SzurubooruResult<UserResource>;
+        #[cfg(feature = "fs")]
+        fn create_user_with_avatar_path(
+            &self,
+            avatar_path: impl AsRef<Path>,
+            new_user: &CreateUpdateUser
+        ) -> SzurubooruResult<UserResource>;
+        fn create_user_with_avatar_bytes(
+            &self,
+            avatar: impl Into<Vec<u8>>,
+            file_name: impl AsRef<str>,
+            new_user: &CreateUpdateUser
+        ) -> SzurubooruResult<UserResource>;
+        fn update_user(&self, name: impl AsRef<str> + Display, update_user: &CreateUpdateUser)
+            -> SzurubooruResult<UserResource>;
+        #[cfg(feature = "fs")]
+        fn update_user_with_avatar_file(
+            &self,
+            name: impl AsRef<str> + Display,
+            avatar: &mut File,
+            file_name: impl AsRef<str>,
+            update_user: &CreateUpdateUser
+        ) -> SzurubooruResult<UserResource>;
+        #[cfg(feature = "fs")]
+        fn update_user_with_avatar_path(
+            &self,
+            name: impl AsRef<str> + Display,
+            avatar_path: impl AsRef<Path>,
+            new_user: &CreateUpdateUser
+        ) -> SzurubooruResult<UserResource>;
+        fn update_user_with_avatar_bytes(
+            &self,
+            name: impl AsRef<str> + Display,
+            avatar: impl Into<Vec<u8>>,
+            file_name: impl AsRef<str>,
+            update_user: &CreateUpdateUser
+        ) -> SzurubooruResult<UserResource>;
+        fn get_user(&self, name: impl AsRef<str> + Display) -> SzurubooruResult<UserResource>;
+        fn whoami(&self) -> SzurubooruResult<UserResource>;
+        fn delete_user(&self, name: impl AsRef<str> + Display, version: u32)
+            -> SzurubooruResult<()>;
+        fn list_user_tokens(&self, name: impl AsRef<str> + Display)
+            -> SzurubooruResult<UnpagedSearchResult<UserAuthTokenResource>>;
+        fn create_user_token(
+            &self,
+            user_name: impl AsRef<str> + Display,
+            create_token: &CreateUpdateUserAuthToken
+        ) -> SzurubooruResult<UserAuthTokenResource>;
+        fn update_user_token(
+            &self,
+            name: &str,
+            token: &str,
+            update_token: &CreateUpdateUserAuthToken
+        ) -> SzurubooruResult<UserAuthTokenResource>;
+        fn delete_user_token(&self, name: &str, token: &str, version: u32)
+            -> SzurubooruResult<()>;
+        fn password_reset_request(&self, email_or_name: impl AsRef<str> + Display)
+            -> SzurubooruResult<()>;
+        fn password_reset_confirm(
+            &self,
+            email_or_name: impl AsRef<str> + Display,
+            token: impl AsRef<str>
+        ) -> SzurubooruResult<TemporaryPassword>;
+        fn complete_password_reset(
+            &self,
+            user_name: &str,
+            token: impl AsRef<str>,
+            new_password: impl AsRef<str>
+        ) -> SzurubooruResult<UserResource>;
+
+        fn list_snapshots(&self, query: Option<&Vec<QueryToken>>)
+            -> SzurubooruResult<PagedSearchResult<SnapshotResource>>;
+        fn list_snapshots_for_type(&self, resource_type: SnapshotResourceType)
+            -> SzurubooruResult<PagedSearchResult<SnapshotResource>>;
+        fn list_snapshots_for_operation(&self, operation: SnapshotOperationType)
+            -> SzurubooruResult<PagedSearchResult<SnapshotResource>>;
+        fn list_snapshots_for(&self, resource_type: SnapshotResourceType, id: &str)
+            -> SzurubooruResult<PagedSearchResult<SnapshotResource>>;
+        fn snapshots_since_id(&self, last_id: &str, max_results: usize)
+            -> SzurubooruResult<Vec<SnapshotResource>>;
+        fn snapshots_since(&self, last: &SnapshotResource, max_results: usize)
+            -> SzurubooruResult<Vec<SnapshotResource>>;
+        fn get_global_info(&self) -> SzurubooruResult<GlobalInfo>;
+        fn can_current_user(&self, privilege: &str) -> SzurubooruResult<bool>;
+        #[cfg(feature = "fs")]
+        fn upload_temporary_file(&self, file: &mut File, file_name: impl AsRef<str>)
+            -> SzurubooruResult<TemporaryFileUpload>;
+        #[cfg(feature = "fs")]
+        fn upload_temporary_file_from_path(&self, file_path: impl AsRef<Path>)
+            -> SzurubooruResult<TemporaryFileUpload>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SzurubooruBlockingClient;
+    use mockito::Server;
+
+    #[test]
+    fn test_blocking_request() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/api/posts")
+            .match_query(mockito::Matcher::UrlEncoded("limit".into(), "1".into()))
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"query": "", "offset": 0, "limit": 1, "total": 0, "results": []}"#)
+            .create();
+
+        let client = SzurubooruBlockingClient::new_anonymous(&server.url(), false).unwrap();
+        let posts = client.with_limit(1).list_posts(None).unwrap();
+
+        mock.assert();
+        assert_eq!(posts.total, 0);
+        assert!(posts.results.is_empty());
+    }
+}
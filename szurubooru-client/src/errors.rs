@@ -7,7 +7,7 @@ use derive_builder::UninitializedFieldError;
 #[cfg(feature = "python")]
 use pyo3::{create_exception, exceptions::PyException, prelude::*};
 
-use reqwest::StatusCode;
+use reqwest::{header::HeaderMap, StatusCode};
 use serde::{Deserialize, Serialize};
 use strum_macros::AsRefStr;
 use thiserror::Error;
@@ -43,7 +43,14 @@ pub enum SzurubooruClientError {
     RequestError(#[source] reqwest::Error),
     /// Error response with a text response from the server
     #[error("Response error {0}: Server reply: {1}")]
-    ResponseError(StatusCode, String),
+    ResponseError(
+        /// The response status
+        StatusCode,
+        /// The response body
+        String,
+        /// The response headers, such as `Retry-After` on a `429 Too Many Requests`
+        HeaderMap,
+    ),
     /// Error parsing the JSON response from the server
     #[error("Response Parsing error: {0}: {1}")]
     ResponseParsingError(
@@ -56,6 +63,9 @@ pub enum SzurubooruClientError {
     /// Error serializing an object as JSON
     #[error("JSON Serialization error: {0}")]
     JSONSerializationError(#[source] serde_json::Error),
+    /// A variable needed by [from_env](crate::SzurubooruClient::from_env) isn't set
+    #[error("Environment variable {0} is not set")]
+    MissingEnvVar(String),
     /// Error when validation fails for one of the Builder types
     #[error("Validation error: {0}")]
     ValidationError(String),
@@ -65,11 +75,51 @@ pub enum SzurubooruClientError {
     /// Error returned by the Szurubooru server
     #[error("Error returned from Szurubooru host: {0:?}")]
     SzurubooruServerError(SzurubooruServerError),
+    /// The server rejected an update or delete because the resource was modified since its
+    /// version was fetched. Re-fetch the resource and try again.
+    /// See [versioning](crate::models::ResourceVersion)
+    #[error("Version conflict: {}", .0.description)]
+    VersionConflict(SzurubooruServerError),
 }
 
 impl From<SzurubooruServerError> for SzurubooruClientError {
     fn from(value: SzurubooruServerError) -> Self {
-        SzurubooruClientError::SzurubooruServerError(value)
+        match value.name {
+            SzurubooruServerErrorType::IntegrityError => {
+                SzurubooruClientError::VersionConflict(value)
+            }
+            _ => SzurubooruClientError::SzurubooruServerError(value),
+        }
+    }
+}
+
+impl SzurubooruClientError {
+    /// Whether the server rejected the request because the resource had been modified since
+    /// its version was fetched. See [versioning](crate::models::ResourceVersion)
+    pub(crate) fn is_version_conflict(&self) -> bool {
+        matches!(self, SzurubooruClientError::VersionConflict(_))
+    }
+
+    /// Whether the server couldn't find the requested tag
+    pub(crate) fn is_tag_not_found(&self) -> bool {
+        matches!(
+            self,
+            SzurubooruClientError::SzurubooruServerError(SzurubooruServerError {
+                name: SzurubooruServerErrorType::TagNotFoundError,
+                ..
+            })
+        )
+    }
+
+    /// Whether the server rejected a new post because it already has one with the same content
+    pub(crate) fn is_already_uploaded(&self) -> bool {
+        matches!(
+            self,
+            SzurubooruClientError::SzurubooruServerError(SzurubooruServerError {
+                name: SzurubooruServerErrorType::PostAlreadyUploadedError,
+                ..
+            })
+        )
     }
 }
 
@@ -97,16 +147,24 @@ impl std::convert::From<SzurubooruClientError> for PyErr {
 /// Type used to represent success or a failure of some kind
 pub type SzurubooruResult<T> = Result<T, SzurubooruClientError>;
 
-#[doc(hidden)]
-impl<T> IntoClientResult<T> for SzuruEither<T, SzurubooruServerError> {
-    fn into_result(self) -> SzurubooruResult<T> {
+impl<T> SzuruEither<T, SzurubooruServerError> {
+    /// Convert a raw server response into a [SzurubooruResult], turning the `Right` variant
+    /// into the matching [SzurubooruClientError]
+    pub fn into_result(self) -> SzurubooruResult<T> {
         match self {
             SzuruEither::Left(v) => Ok(v),
-            SzuruEither::Right(e) => Err(SzurubooruClientError::SzurubooruServerError(e)),
+            SzuruEither::Right(e) => Err(e.into()),
         }
     }
 }
 
+#[doc(hidden)]
+impl<T> IntoClientResult<T> for SzuruEither<T, SzurubooruServerError> {
+    fn into_result(self) -> SzurubooruResult<T> {
+        SzuruEither::into_result(self)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq)]
 /// An error type returned by the server
 pub enum SzurubooruServerErrorType {
@@ -231,4 +289,57 @@ mod test {
         assert_eq!(sse.title, "Validation Error");
         assert_eq!(sse.description, "Some sort of validation error");
     }
+
+    #[test]
+    fn test_parse_localized_server_error() {
+        let json_response = r#"{
+        "name": "PostNotFoundError",
+        "title": "Beitrag nicht gefunden",
+        "description": "Beitrag \u00bb42\u00ab existiert nicht – bitte erneut versuchen."
+        }"#;
+
+        let sse = serde_json::from_str::<SzurubooruServerError>(json_response)
+            .expect("Failed to parse the JSON response");
+        assert_eq!(sse.title, "Beitrag nicht gefunden");
+        assert_eq!(
+            sse.description,
+            "Beitrag »42« existiert nicht – bitte erneut versuchen."
+        );
+    }
+
+    #[test]
+    fn test_version_conflict() {
+        let json_response = r#"{
+        "name": "IntegrityError",
+        "title": "Integrity violation",
+        "description": "Someone else modified this in the meantime. Please try again."
+        }"#;
+
+        let sse = serde_json::from_str::<SzurubooruServerError>(json_response)
+            .expect("Failed to parse the JSON response");
+        let err = SzurubooruClientError::from(sse);
+        assert!(matches!(err, SzurubooruClientError::VersionConflict(_)));
+        assert!(err.is_version_conflict());
+        assert!(!SzurubooruClientError::ValidationError("foo".to_string()).is_version_conflict());
+    }
+
+    #[test]
+    fn test_either_into_result() {
+        let json_response = r#"{
+        "name": "TagNotFoundError",
+        "title": "Not found",
+        "description": "Tag not found"
+        }"#;
+
+        let either = serde_json::from_str::<SzuruEither<u32, SzurubooruServerError>>(json_response)
+            .expect("Failed to parse the JSON response");
+        assert!(either.left().is_none());
+        assert_eq!(either.right().map(|e| e.title.as_str()), Some("Not found"));
+        assert!(either.into_result().is_err());
+
+        let either = SzuruEither::<u32, SzurubooruServerError>::Left(42);
+        assert_eq!(either.left(), Some(&42));
+        assert!(either.right().is_none());
+        assert_eq!(either.into_result().unwrap(), 42);
+    }
 }
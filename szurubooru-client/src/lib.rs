@@ -22,14 +22,33 @@
 //! ```
 //!
 //! For all other methods for making the requests, see the documentation.
+//!
+//! # Features
+//!
+//! - `fs` (enabled by default): methods that read from or write to the filesystem, such as
+//!   [create_post_from_file_path](SzurubooruRequest::create_post_from_file_path) and
+//!   [download_image_to_path](SzurubooruRequest::download_image_to_path). Disable it to build
+//!   for targets without a filesystem, like `wasm32-unknown-unknown`, and use the byte-based
+//!   methods such as [create_post_from_bytes](SzurubooruRequest::create_post_from_bytes) and
+//!   [get_image_bytes](SzurubooruRequest::get_image_bytes) instead. On `wasm32` the browser
+//!   handles connections, TLS and proxies, so [SzurubooruClientBuilder] leaves out its pooling,
+//!   HTTP/2 and proxy options there
+//! - `blocking`: a synchronous `SzurubooruBlockingClient` in the `blocking` module, for callers
+//!   without an async runtime
+//! - `gzip` and `brotli`: let [SzurubooruClientBuilder] ask for compressed responses with its
+//!   `gzip` and `brotli` options, which saves bandwidth on large listings over slow links
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_crate_level_docs)]
 
 /// Core client module
 pub mod client;
+pub use client::PostEditor;
 pub use client::SzurubooruClient;
+pub use client::SzurubooruClientBuilder;
 pub use client::SzurubooruRequest;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod errors;
 pub use errors::SzurubooruResult;
 pub mod models;
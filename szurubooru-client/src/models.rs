@@ -6,11 +6,12 @@
 //! more information.
 
 use std::cmp::Ordering;
-use crate::errors::SzurubooruClientError;
+use crate::errors::{SzurubooruClientError, SzurubooruResult};
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use strum_macros::AsRefStr;
 
 #[cfg(feature = "python")]
@@ -28,6 +29,24 @@ pub enum SzuruEither<L, R> {
     Right(R),
 }
 
+impl<L, R> SzuruEither<L, R> {
+    /// Returns a reference to the `Left` value, if this is a `Left`
+    pub fn left(&self) -> Option<&L> {
+        match self {
+            SzuruEither::Left(l) => Some(l),
+            SzuruEither::Right(_) => None,
+        }
+    }
+
+    /// Returns a reference to the `Right` value, if this is a `Right`
+    pub fn right(&self) -> Option<&R> {
+        match self {
+            SzuruEither::Left(_) => None,
+            SzuruEither::Right(r) => Some(r),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 /// A result of search operation that doesn't involve paging
 pub struct UnpagedSearchResult<T> {
@@ -47,7 +66,7 @@ impl<T: WithBaseURL> WithBaseURL for UnpagedSearchResult<T> {
 /// A result of search operation that involves paging
 ///
 /// Use [offset](crate::SzurubooruRequest::with_offset) and [limit](crate::SzurubooruRequest::with_limit)
-/// to fetch the next page
+/// to fetch the next page, or let [next_page](crate::SzurubooruRequest::next_page) work them out
 pub struct PagedSearchResult<T> {
     /// The original query for the request
     pub query: String,
@@ -56,7 +75,7 @@ pub struct PagedSearchResult<T> {
     /// The maximum number of `T` to return
     pub limit: u32,
     /// The total number of `T` that match the [query](PagedSearchResult::query)
-    pub total: u32,
+    pub total: u64,
     /// The results themselves
     pub results: Vec<T>,
 }
@@ -100,7 +119,7 @@ pub struct MicroTagResource {
     /// The category this tag belongs to
     pub category: String,
     /// The number of times this tag has been used
-    pub usages: u32,
+    pub usages: u64,
 }
 
 #[cfg(feature = "python")]
@@ -134,7 +153,7 @@ impl MicroTagResource {
 /// encouraged to notify the user about the situation.
 pub struct ResourceVersion {
     /// The version itself
-    pub version: DateTime<Utc>,
+    pub version: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
@@ -146,7 +165,7 @@ pub struct ResourceVersion {
 /// A single tag. Tags are used to let users search for posts.
 pub struct TagResource {
     /// resource version. See [versioning](ResourceVersion)
-    pub version: DateTime<Utc>,
+    pub version: u32,
     /// a list of tag names (aliases). Tagging a post with any name will automatically assign
     /// the first name from this list.
     pub names: Option<Vec<String>>,
@@ -163,12 +182,37 @@ pub struct TagResource {
     /// time the tag was edited
     pub last_edit_time: Option<DateTime<Utc>>,
     /// the number of posts the tag was used in
-    pub usages: Option<u32>,
+    pub usages: Option<u64>,
     /// the tag description (instructions how to use, history etc.) The client should render
     /// is as Markdown
     pub description: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, AsRefStr, Eq, PartialEq)]
+#[strum(serialize_all = "camelCase")]
+/// A field of [TagResource] that can be selected with
+/// [with_tag_fields](crate::SzurubooruRequest::with_tag_fields)
+pub enum TagField {
+    /// Resource version
+    Version,
+    /// Tag names and aliases
+    Names,
+    /// Tag category name
+    Category,
+    /// Tags implied by this tag
+    Implications,
+    /// Tags suggested by this tag
+    Suggestions,
+    /// Time the tag was created
+    CreationTime,
+    /// Time the tag was last edited
+    LastEditTime,
+    /// Number of posts using the tag
+    Usages,
+    /// Tag description
+    Description,
+}
+
 #[cfg(feature = "python")]
 #[cfg_attr(all(feature = "python"), pymethods)]
 #[doc(hidden)]
@@ -202,7 +246,7 @@ pub struct CreateUpdateTag {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default)]
     /// resource version. See [versioning](ResourceVersion)
-    pub version: Option<DateTime<Utc>>,
+    pub version: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default)]
     /// Tag names and aliases, must match `tag_name_regex` from the server's configuration
@@ -240,7 +284,7 @@ pub struct TagCategoryResource {
     /// The display color of the tag category
     pub color: Option<String>,
     /// How many tags is the given category used with
-    pub usages: Option<u32>,
+    pub usages: Option<u64>,
     /// The order in which tags with this category are displayed, ascending
     pub order: Option<u32>,
     /// Whether the tag category is the default one
@@ -259,7 +303,6 @@ impl TagCategoryResource {
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
 #[builder(setter(strip_option), build_fn(error = "SzurubooruClientError"))]
-
 /// Used for creating or updating a Tag Category
 pub struct CreateUpdateTagCategory {
     /// Resource version. See [versioning](ResourceVersion)
@@ -285,6 +328,10 @@ pub struct CreateUpdateTagCategory {
 #[serde(rename_all = "camelCase")]
 /// Removes source tag and merges all of its usages, suggestions and implications to the target tag.
 /// Other tag properties such as category and aliases do not get transferred and are discarded.
+///
+/// The builder's setters are named after the fields, like
+/// [remove_tag](MergeTagsBuilder::remove_tag). The API's own names, like
+/// [remove](MergeTagsBuilder::remove), are accepted as aliases
 pub struct MergeTags {
     /// Version of the tag to remove
     #[serde(rename = "removeVersion")]
@@ -299,6 +346,23 @@ pub struct MergeTags {
     pub merge_to_tag: String,
 }
 
+impl MergeTagsBuilder {
+    /// Alias of [remove_tag_version](Self::remove_tag_version)
+    pub fn remove_version(&mut self, value: u32) -> &mut Self {
+        self.remove_tag_version(value)
+    }
+
+    /// Alias of [remove_tag](Self::remove_tag)
+    pub fn remove(&mut self, value: String) -> &mut Self {
+        self.remove_tag(value)
+    }
+
+    /// Alias of [merge_to_tag](Self::merge_to_tag)
+    pub fn merge_to(&mut self, value: String) -> &mut Self {
+        self.merge_to_tag(value)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(
     all(feature = "python"),
@@ -309,7 +373,7 @@ pub struct TagSibling {
     /// The related tag
     pub tag: TagResource,
     /// How many times a given tag appears with the given tag
-    pub occurrences: u32,
+    pub occurrences: u64,
 }
 
 #[cfg(feature = "python")]
@@ -322,7 +386,7 @@ impl TagSibling {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, AsRefStr, Eq, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, AsRefStr)]
 #[cfg_attr(
     all(feature = "python"),
     pyclass(eq, eq_int, module = "szurubooru_client.models")
@@ -330,6 +394,9 @@ impl TagSibling {
 #[strum(serialize_all = "camelCase")]
 #[serde(rename_all = "camelCase")]
 /// The type of post
+///
+/// Aliases compare equal to the type they stand for, so `PostType::Webm == PostType::Video`.
+/// Use [normalized](PostType::normalized) to match on the canonical types only
 pub enum PostType {
     /// Image post
     Image,
@@ -345,10 +412,30 @@ pub enum PostType {
     Swf,
     /// Video post of some type. See the mime type for more information
     Video,
-    /// Webm container type
+    /// Alias of [Video](PostType::Video)
     Webm,
 }
 
+impl PostType {
+    /// The canonical type this is an alias of, or the type itself if it isn't an alias
+    pub fn normalized(&self) -> PostType {
+        match self {
+            PostType::Image => PostType::Image,
+            PostType::Animation | PostType::Animated | PostType::Anim => PostType::Animation,
+            PostType::Flash | PostType::Swf => PostType::Flash,
+            PostType::Video | PostType::Webm => PostType::Video,
+        }
+    }
+}
+
+impl PartialEq for PostType {
+    fn eq(&self, other: &Self) -> bool {
+        std::mem::discriminant(&self.normalized()) == std::mem::discriminant(&other.normalized())
+    }
+}
+
+impl Eq for PostType {}
+
 #[derive(Debug, Clone, Serialize, Deserialize, AsRefStr, Eq, PartialEq)]
 #[cfg_attr(
     all(feature = "python"),
@@ -420,7 +507,7 @@ pub(crate) struct PostId {
 /// A post resource
 pub struct PostResource {
     /// Resource version. See [versioning](ResourceVersion)
-    pub version: Option<DateTime<Utc>>,
+    pub version: Option<u32>,
     /// The post identifier
     pub id: Option<u32>,
     /// Time the post was created
@@ -455,6 +542,11 @@ pub struct PostResource {
     pub tags: Option<Vec<MicroTagResource>>,
     /// A list of related posts.
     pub relations: Option<Vec<MicroPostResource>>,
+    /// The full resources for [relations](PostResource::relations). The server never sends this,
+    /// it's only filled in by requests made
+    /// [with_expanded_relations](crate::SzurubooruRequest::with_expanded_relations)
+    #[serde(skip)]
+    pub expanded_relations: Option<Vec<PostResource>>,
     /// A list of post annotations
     pub notes: Option<Vec<NoteResource>>,
     /// Who created the post
@@ -466,17 +558,17 @@ pub struct PostResource {
     /// Whether the authenticated user has given post in their favorites
     pub own_favorite: Option<bool>,
     /// How many tags the post is tagged with
-    pub tag_count: Option<u32>,
+    pub tag_count: Option<u64>,
     /// How many users have the post in their favorites
-    pub favorite_count: Option<u32>,
+    pub favorite_count: Option<u64>,
     /// How many comments are filed under that post
-    pub comment_count: Option<u32>,
+    pub comment_count: Option<u64>,
     /// How many notes the post has
-    pub note_count: Option<u32>,
+    pub note_count: Option<u64>,
     /// How many times has the post been featured
-    pub feature_count: Option<u32>,
+    pub feature_count: Option<u64>,
     /// How many posts are related to this post
-    pub relation_count: Option<u32>,
+    pub relation_count: Option<u64>,
     /// The last time the post was featured
     pub last_feature_time: Option<DateTime<Utc>>,
     /// List of users who have favorited this post
@@ -492,6 +584,83 @@ pub struct PostResource {
     pub pools: Option<Vec<PoolResource>>,
 }
 
+#[derive(Debug, Clone, Copy, AsRefStr, Eq, PartialEq)]
+#[strum(serialize_all = "camelCase")]
+/// A field of [PostResource] that can be selected with
+/// [with_post_fields](crate::SzurubooruRequest::with_post_fields)
+pub enum PostField {
+    /// Resource version
+    Version,
+    /// Post ID
+    Id,
+    /// Time the post was created
+    CreationTime,
+    /// Time the post was last edited
+    LastEditTime,
+    /// Post safety
+    Safety,
+    /// Post type ([post_type](PostResource::post_type))
+    #[strum(serialize = "type")]
+    Type,
+    /// Post source
+    Source,
+    /// SHA1 checksum of the content
+    Checksum,
+    /// MD5 checksum of the content
+    #[strum(serialize = "checksumMD5")]
+    ChecksumMd5,
+    /// Size of the content in bytes
+    FileSize,
+    /// Width of the content
+    CanvasWidth,
+    /// Height of the content
+    CanvasHeight,
+    /// URL of the post content
+    ContentUrl,
+    /// URL of the post thumbnail
+    ThumbnailUrl,
+    /// Post flags
+    Flags,
+    /// Post tags
+    Tags,
+    /// Related posts
+    Relations,
+    /// Post notes
+    Notes,
+    /// Uploader of the post
+    User,
+    /// Post score
+    Score,
+    /// Score given by the authenticated user
+    OwnScore,
+    /// Whether the authenticated user favorited the post
+    OwnFavorite,
+    /// Number of tags
+    TagCount,
+    /// Number of favorites
+    FavoriteCount,
+    /// Number of comments
+    CommentCount,
+    /// Number of notes
+    NoteCount,
+    /// Number of times the post was featured
+    FeatureCount,
+    /// Number of related posts
+    RelationCount,
+    /// Time the post was last featured
+    LastFeatureTime,
+    /// Users who favorited the post
+    FavoritedBy,
+    /// Whether the post has a custom thumbnail
+    HasCustomThumbnail,
+    /// MIME type of the content
+    MimeType,
+    /// Post comments
+    Comments,
+    /// Pools containing the post
+    Pools,
+}
+
 #[cfg(feature = "python")]
 #[cfg_attr(all(feature = "python"), pymethods)]
 #[doc(hidden)]
@@ -502,6 +671,169 @@ impl PostResource {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(
+    all(feature = "python"),
+    pyclass(get_all, module = "szurubooru_client.models")
+)]
+#[serde(rename_all = "camelCase")]
+/// The rating and favorite state of a post, as returned by
+/// [get_post_interaction_state](crate::SzurubooruRequest::get_post_interaction_state)
+pub struct PostInteraction {
+    /// Whether the authenticated user has the post in their favorites
+    pub own_favorite: bool,
+    /// The authenticated user's score for the post
+    pub own_score: i32,
+    /// The collective score of the post
+    pub score: i32,
+    /// How many users have the post in their favorites
+    pub favorite_count: u64,
+}
+
+#[cfg(feature = "python")]
+#[cfg_attr(all(feature = "python"), pymethods)]
+#[doc(hidden)]
+impl PostInteraction {
+    /// Generates a representative string of this resource
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl PostResource {
+    /// Compares this post against a newer copy of it, reporting what changed. Fields that weren't
+    /// selected on either side are treated as empty.
+    ///
+    /// Tags are compared by their primary name and relations by post ID, so the order they're
+    /// returned in doesn't matter
+    pub fn diff(&self, other: &PostResource) -> PostDiff {
+        fn tag_names(post: &PostResource) -> Vec<String> {
+            post.tags
+                .iter()
+                .flatten()
+                .filter_map(|t| t.names.first().cloned())
+                .collect()
+        }
+        fn relation_ids(post: &PostResource) -> Vec<u32> {
+            post.relations.iter().flatten().map(|r| r.id).collect()
+        }
+
+        let (old_tags, new_tags) = (tag_names(self), tag_names(other));
+        let (old_rels, new_rels) = (relation_ids(self), relation_ids(other));
+
+        PostDiff {
+            tags_added: new_tags
+                .iter()
+                .filter(|t| !old_tags.contains(t))
+                .cloned()
+                .collect(),
+            tags_removed: old_tags
+                .iter()
+                .filter(|t| !new_tags.contains(t))
+                .cloned()
+                .collect(),
+            safety_changed: self.safety != other.safety,
+            source_changed: self.source != other.source,
+            relations_added: new_rels
+                .iter()
+                .filter(|r| !old_rels.contains(r))
+                .copied()
+                .collect(),
+            relations_removed: old_rels
+                .iter()
+                .filter(|r| !new_rels.contains(r))
+                .copied()
+                .collect(),
+            notes_changed: self.notes.as_deref().unwrap_or_default()
+                != other.notes.as_deref().unwrap_or_default(),
+        }
+    }
+
+    /// Copies this post's tags, safety, source, relations, notes and flags into a
+    /// [CreateUpdatePost], for uploading new content with the same metadata.
+    ///
+    /// The version, ID and content aren't copied, so the content (a file, URL or
+    /// [content_token](CreateUpdatePost::content_token)) still has to be supplied when uploading
+    pub fn as_create_template(&self) -> CreateUpdatePost {
+        CreateUpdatePost {
+            version: None,
+            tags: self.tags.as_ref().map(|tags| {
+                tags.iter()
+                    .filter_map(|t| t.names.first().cloned())
+                    .collect()
+            }),
+            safety: self.safety.clone(),
+            source: self.source.clone(),
+            relations: self
+                .relations
+                .as_ref()
+                .map(|rels| rels.iter().map(|r| r.id).collect()),
+            notes: self.notes.clone(),
+            flags: self.flags.clone(),
+            content_url: None,
+            content_token: None,
+            anonymous: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// The changes between two copies of the same post. See [PostResource::diff]
+pub struct PostDiff {
+    /// Primary names of the tags the newer post has that the older one doesn't
+    pub tags_added: Vec<String>,
+    /// Primary names of the tags the older post has that the newer one doesn't
+    pub tags_removed: Vec<String>,
+    /// Whether the [safety](PostResource::safety) changed
+    pub safety_changed: bool,
+    /// Whether the [source](PostResource::source) changed
+    pub source_changed: bool,
+    /// IDs of posts that became related
+    pub relations_added: Vec<u32>,
+    /// IDs of posts that are no longer related
+    pub relations_removed: Vec<u32>,
+    /// Whether any of the [notes](PostResource::notes) were added, removed or edited
+    pub notes_changed: bool,
+}
+
+impl PostDiff {
+    /// Whether the two posts are the same as far as the compared fields are concerned
+    pub fn is_empty(&self) -> bool {
+        self == &PostDiff::default()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Posts connected through their relations, as built by
+/// [build_relation_graph](crate::SzurubooruRequest::build_relation_graph)
+pub struct RelationGraph {
+    /// The IDs of the posts in the graph, in the order they were reached
+    pub nodes: Vec<u32>,
+    /// The relations between posts. Relations go both ways, so each is listed once with the
+    /// lower post ID first
+    pub edges: Vec<(u32, u32)>,
+}
+
+impl RelationGraph {
+    /// Whether the given post is part of the graph
+    pub fn contains(&self, post_id: u32) -> bool {
+        self.nodes.contains(&post_id)
+    }
+
+    /// The IDs of the posts directly related to the given post
+    pub fn neighbors(&self, post_id: u32) -> impl Iterator<Item = u32> + '_ {
+        self.edges.iter().filter_map(move |&(a, b)| {
+            if a == post_id {
+                Some(b)
+            } else if b == post_id {
+                Some(a)
+            } else {
+                None
+            }
+        })
+    }
+}
+
 impl WithBaseURL for PostResource {
     fn with_base_url(self, url: &str) -> Self {
         let curl = self.content_url.map(|cu| {
@@ -545,7 +877,7 @@ pub struct CreateUpdatePost {
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Resource version. See [versioning](ResourceVersion)
     #[builder(default)]
-    pub version: Option<DateTime<Utc>>,
+    pub version: Option<u32>,
     /// Tags to use for this post. If specified tags do not exist yet, they will be automatically
     /// created. Tags created automatically have no implications, no suggestions, one name and
     /// their category is set to the first tag category found
@@ -587,6 +919,116 @@ pub struct CreateUpdatePost {
     pub anonymous: Option<bool>,
 }
 
+impl CreateUpdatePost {
+    /// Checks the fields the server requires when creating a post, currently just
+    /// [safety](CreateUpdatePost::safety). Every `create_post_*` method runs this before
+    /// uploading anything, while updates don't need it
+    pub fn validate_for_create(&self) -> SzurubooruResult<()> {
+        if self.safety.is_none() {
+            return Err(SzurubooruClientError::ValidationError(
+                "Safety must be set when creating a post".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks that [content_url](CreateUpdatePost::content_url), if set, is an absolute `http`
+    /// or `https` URL the server can download from. Local paths and `file://` URLs are
+    /// rejected here rather than with an unhelpful error from the server
+    pub fn validate_content_url(&self) -> SzurubooruResult<()> {
+        let Some(content_url) = self.content_url.as_deref() else {
+            return Ok(());
+        };
+        match url::Url::parse(content_url) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") => Ok(()),
+            _ => Err(SzurubooruClientError::ValidationError(format!(
+                "contentUrl must be an http or https URL, got {content_url:?}"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+/// A set of changes to apply to a post in a single update with
+/// [patch_post](crate::SzurubooruRequest::patch_post).
+///
+/// Fields left as `None` are left unchanged on the server, while `Some` replaces the field
+/// entirely. For list fields that means `Some(vec![])` clears them.
+pub struct PostPatch {
+    /// The new safety rating
+    pub safety: Option<PostSafety>,
+    /// The new source, use an empty string to clear it
+    pub source: Option<String>,
+    /// The full list of tags the post should have
+    pub tags: Option<Vec<String>>,
+    /// The full list of related post IDs
+    pub relations: Option<Vec<u32>>,
+    /// The full list of post flags
+    pub flags: Option<Vec<String>>,
+    /// The full list of notes
+    pub notes: Option<Vec<NoteResource>>,
+}
+
+impl PostPatch {
+    /// Builds the [CreateUpdatePost] that applies this patch to the given post version
+    pub fn to_update(&self, version: u32) -> CreateUpdatePost {
+        CreateUpdatePost {
+            version: Some(version),
+            tags: self.tags.clone(),
+            safety: self.safety.clone(),
+            source: self.source.clone(),
+            relations: self.relations.clone(),
+            notes: self.notes.clone(),
+            flags: self.flags.clone(),
+            content_url: None,
+            content_token: None,
+            anonymous: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// One line of a JSONL manifest read by
+/// [import_posts_jsonl](crate::SzurubooruRequest::import_posts_jsonl): the post's metadata
+/// alongside where its content comes from.
+///
+/// Exactly one of [content_path](PostImportLine::content_path) or the post's
+/// [content_url](CreateUpdatePost::content_url) must be set
+///
+/// ```json
+/// {"tags": ["cat"], "safety": "safe", "contentPath": "export/1.jpg"}
+/// {"tags": ["dog"], "safety": "safe", "contentUrl": "https://example.com/2.jpg"}
+/// ```
+pub struct PostImportLine {
+    /// The post to create
+    #[serde(flatten)]
+    pub post: CreateUpdatePost,
+    /// A local file to upload as the post's content. Relative paths are resolved against the
+    /// current directory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_path: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+/// What happened to a post described by a [PostImportLine]
+pub enum PostImportOutcome {
+    /// The post was created
+    Created(Box<PostResource>),
+    /// The server already has a post with the same content, so nothing was uploaded
+    AlreadyUploaded,
+}
+
+#[derive(Debug)]
+/// The result of importing one line of a JSONL manifest with
+/// [import_posts_jsonl](crate::SzurubooruRequest::import_posts_jsonl)
+pub struct PostImportResult {
+    /// The 1-based line number in the manifest
+    pub line_number: usize,
+    /// What happened to the post, or why the line couldn't be imported
+    pub result: SzurubooruResult<PostImportOutcome>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 /// A token representing a temporary file upload
@@ -603,6 +1045,10 @@ pub struct TemporaryFileUpload {
 /// the content of the source post; otherwise it remains unchanged. Source post properties such as
 /// its safety, source, whether to loop the video and other scalar values do not get transferred
 /// and are discarded.
+///
+/// The builder's setters are named after the fields, like
+/// [remove_post](MergePostBuilder::remove_post). The API's own names, like
+/// [remove](MergePostBuilder::remove), are accepted as aliases
 pub struct MergePost {
     /// The version of the post to remove
     #[serde(rename = "removeVersion")]
@@ -620,6 +1066,28 @@ pub struct MergePost {
     pub replace_post_content: bool,
 }
 
+impl MergePostBuilder {
+    /// Alias of [remove_post_version](Self::remove_post_version)
+    pub fn remove_version(&mut self, value: u32) -> &mut Self {
+        self.remove_post_version(value)
+    }
+
+    /// Alias of [remove_post](Self::remove_post)
+    pub fn remove(&mut self, value: u32) -> &mut Self {
+        self.remove_post(value)
+    }
+
+    /// Alias of [merge_to_post](Self::merge_to_post)
+    pub fn merge_to(&mut self, value: u32) -> &mut Self {
+        self.merge_to_post(value)
+    }
+
+    /// Alias of [replace_post_content](Self::replace_post_content)
+    pub fn replace_content(&mut self, value: bool) -> &mut Self {
+        self.replace_post_content(value)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[doc(hidden)]
 pub struct RateResource {
@@ -687,6 +1155,49 @@ pub enum UserRank {
     Administrator,
 }
 
+/// The level of the `anonymous` pseudo-rank, below every [UserRank]
+pub(crate) const ANONYMOUS_RANK_LEVEL: u8 = 0;
+
+impl UserRank {
+    /// Where this rank sits in the server's hierarchy, for comparing against privileges
+    pub(crate) fn level(&self) -> u8 {
+        match self {
+            UserRank::Restricted => 1,
+            UserRank::Regular => 2,
+            UserRank::Power => 3,
+            UserRank::Moderator => 4,
+            UserRank::Administrator => 5,
+        }
+    }
+}
+
+impl Ord for UserRank {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.level().cmp(&other.level())
+    }
+}
+
+impl PartialOrd for UserRank {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The level of a rank name as used in the server's privilege config. `nobody` is above every
+/// rank, so privileges that require it can't be used by anyone
+pub(crate) fn rank_level(rank: &str) -> Option<u8> {
+    match rank {
+        "anonymous" => Some(ANONYMOUS_RANK_LEVEL),
+        "restricted" => Some(UserRank::Restricted.level()),
+        "regular" => Some(UserRank::Regular.level()),
+        "power" => Some(UserRank::Power.level()),
+        "moderator" => Some(UserRank::Moderator.level()),
+        "administrator" => Some(UserRank::Administrator.level()),
+        "nobody" => Some(UserRank::Administrator.level() + 1),
+        _ => None,
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, AsRefStr, Eq, PartialEq)]
 #[cfg_attr(
     all(feature = "python"),
@@ -702,7 +1213,9 @@ pub enum UserAvatarStyle {
     Manual,
 }
 
-// Because pyo3 get_all doesn't let you exclude fields we have to define the fields twice
+// Because pyo3 get_all doesn't let you exclude fields we have to define the fields twice.
+// Keep both copies identical; `test_python_field_parity` builds this with every field so a
+// field missing from either configuration fails to compile
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(all(feature = "python"), pyclass(module = "szurubooru_client.models"))]
 #[serde(rename_all = "camelCase")]
@@ -743,22 +1256,18 @@ pub struct UserResource {
     /// The last login time
     #[cfg(feature = "python")]
     #[pyo3(get)]
-    #[serde(rename = "last-login-time")]
     pub last_login_time: Option<DateTime<Utc>>,
 
     /// The last login time
     #[cfg(not(feature = "python"))]
-    #[serde(rename = "last-login-time")]
     pub last_login_time: Option<DateTime<Utc>>,
 
     /// The user registration time
-    #[serde(rename = "creation-time")]
     #[cfg(feature = "python")]
     #[pyo3(get)]
     pub creation_time: Option<DateTime<Utc>>,
 
     /// The user registration time
-    #[serde(rename = "creation-time")]
     #[cfg(not(feature = "python"))]
     pub creation_time: Option<DateTime<Utc>>,
 
@@ -783,38 +1292,64 @@ pub struct UserResource {
     /// Number of comments
     #[cfg(feature = "python")]
     #[pyo3(get)]
-    #[serde(rename = "comment-count")]
-    pub comment_count: Option<u32>,
+    pub comment_count: Option<u64>,
 
     /// Number of comments
     #[cfg(not(feature = "python"))]
-    #[serde(rename = "comment-count")]
-    pub comment_count: Option<u32>,
+    pub comment_count: Option<u64>,
 
     /// Number of uploaded posts
     #[cfg(feature = "python")]
     #[pyo3(get)]
-    #[serde(rename = "uploaded-post-count")]
-    pub uploaded_post_count: Option<u32>,
+    pub uploaded_post_count: Option<u64>,
 
     /// Number of uploaded posts
     #[cfg(not(feature = "python"))]
-    #[serde(rename = "uploaded-post-count")]
-    pub uploaded_post_count: Option<u32>,
+    pub uploaded_post_count: Option<u64>,
 
     /// Number of liked posts. It is available only if the request is authenticated by the same
     /// user. If it's unavailable, the server returns `false`
-    #[serde(rename = "liked-post-count")]
-    pub liked_post_count: Option<SzuruEither<u32, bool>>,
+    pub liked_post_count: Option<SzuruEither<u64, bool>>,
 
     /// Number of disliked posts. It is available only if the request is authenticated by the same
     /// user. If it's unavailable, the server returns `false`.
-    #[serde(rename = "disliked-post-count")]
-    pub disliked_post_count: Option<SzuruEither<u32, bool>>,
+    pub disliked_post_count: Option<SzuruEither<u64, bool>>,
 
     /// Number of favorited posts
-    #[serde(rename = "favorite-post-count")]
-    pub favorite_post_count: Option<SzuruEither<u32, bool>>,
+    pub favorite_post_count: Option<SzuruEither<u64, bool>>,
+}
+
+#[derive(Debug, Clone, Copy, AsRefStr, Eq, PartialEq)]
+#[strum(serialize_all = "camelCase")]
+/// A field of [UserResource] that can be selected with
+/// [with_user_fields](crate::SzurubooruRequest::with_user_fields)
+pub enum UserField {
+    /// Resource version
+    Version,
+    /// User name
+    Name,
+    /// User email
+    Email,
+    /// User rank
+    Rank,
+    /// Time the user last logged in
+    LastLoginTime,
+    /// Time the user was created
+    CreationTime,
+    /// Avatar style
+    AvatarStyle,
+    /// URL of the avatar
+    AvatarUrl,
+    /// Number of comments
+    CommentCount,
+    /// Number of uploaded posts
+    UploadedPostCount,
+    /// Number of liked posts
+    LikedPostCount,
+    /// Number of disliked posts
+    DislikedPostCount,
+    /// Number of favorited posts
+    FavoritePostCount,
 }
 
 #[cfg(feature = "python")]
@@ -830,45 +1365,66 @@ impl UserResource {
     #[pyo3(name = "email")]
     /// Returns this resource's email field, if the current user has permission to see it
     pub fn email_py(&self) -> PyResult<Option<String>> {
-        match &self.email {
-            None => Ok(None),
-            Some(SzuruEither::Left(s)) => Ok(Some(s.to_string())),
-            Some(SzuruEither::Right(_)) => Ok(None),
-        }
+        Ok(self.email_opt().map(str::to_string))
     }
 
     #[getter]
     #[pyo3(name = "liked_post_count")]
     /// Returns this resource's liked_post_count, if the current user has permission to see it
-    pub fn liked_post_count_py(&self) -> PyResult<Option<u32>> {
-        match &self.liked_post_count {
-            None => Ok(None),
-            Some(SzuruEither::Left(s)) => Ok(Some(*s)),
-            Some(SzuruEither::Right(_)) => Ok(None),
-        }
+    pub fn liked_post_count_py(&self) -> PyResult<Option<u64>> {
+        Ok(self.liked_post_count_opt())
     }
 
     #[getter]
     #[pyo3(name = "disliked_post_count")]
     /// Returns this resource's disliked_post_count, if the current user has permission to see it
-    pub fn disliked_post_count_py(&self) -> PyResult<Option<u32>> {
-        match &self.disliked_post_count {
-            None => Ok(None),
-            Some(SzuruEither::Left(s)) => Ok(Some(*s)),
-            Some(SzuruEither::Right(_)) => Ok(None),
-        }
+    pub fn disliked_post_count_py(&self) -> PyResult<Option<u64>> {
+        Ok(self.disliked_post_count_opt())
     }
 
     #[getter]
     #[pyo3(name = "favorite_post_count")]
     /// Returns this resource's favorite_post_count, if the current user has permission to see it
-    pub fn favorite_post_count_py(&self) -> PyResult<Option<u32>> {
-        match &self.favorite_post_count {
-            None => Ok(None),
-            Some(SzuruEither::Left(s)) => Ok(Some(*s)),
-            Some(SzuruEither::Right(_)) => Ok(None),
+    pub fn favorite_post_count_py(&self) -> PyResult<Option<u64>> {
+        Ok(self.favorite_post_count_opt())
+    }
+}
+
+impl UserResource {
+    /// The user's email, or [None] if they haven't set one or the current user isn't permitted
+    /// to see it
+    pub fn email_opt(&self) -> Option<&str> {
+        match &self.email {
+            Some(SzuruEither::Left(s)) => Some(s),
+            Some(SzuruEither::Right(_)) | None => None,
         }
     }
+
+    /// The number of posts the user liked, or [None] if the current user isn't permitted to see
+    /// it
+    pub fn liked_post_count_opt(&self) -> Option<u64> {
+        permitted_count(&self.liked_post_count)
+    }
+
+    /// The number of posts the user disliked, or [None] if the current user isn't permitted to
+    /// see it
+    pub fn disliked_post_count_opt(&self) -> Option<u64> {
+        permitted_count(&self.disliked_post_count)
+    }
+
+    /// The number of posts the user favorited, or [None] if the current user isn't permitted to
+    /// see it
+    pub fn favorite_post_count_opt(&self) -> Option<u64> {
+        permitted_count(&self.favorite_post_count)
+    }
+}
+
+/// Unwrap a count the server replaces with `false` when it isn't visible to the current user
+fn permitted_count(count: &Option<SzuruEither<u64, bool>>) -> Option<u64> {
+    match count {
+        Some(SzuruEither::Left(c)) => Some(*c),
+        Some(SzuruEither::Right(_)) | None => None,
+    }
 }
 
 impl WithBaseURL for UserResource {
@@ -958,7 +1514,7 @@ impl WithBaseURL for MicroUserResource {
     all(feature = "python"),
     pyclass(get_all, module = "szurubooru_client.models")
 )]
-#[serde(rename_all = "kebab-case")]
+#[serde(rename_all = "camelCase")]
 /// A single user token
 pub struct UserAuthTokenResource {
     /// A micro user resource
@@ -1002,7 +1558,7 @@ impl WithBaseURL for UserAuthTokenResource {
 
 #[derive(Debug, Clone, Serialize, Deserialize, Builder, Default)]
 #[builder(setter(into, strip_option), build_fn(error = "SzurubooruClientError"))]
-#[serde(rename_all = "kebab-case")]
+#[serde(rename_all = "camelCase")]
 /// `struct` to create or update a UserAuthToken. `version` is only required when updating an
 /// existing resource
 pub struct CreateUpdateUserAuthToken {
@@ -1048,6 +1604,8 @@ pub struct TemporaryPassword {
 #[serde(rename_all = "camelCase")]
 /// Simple server configuration
 pub struct GlobalInfoConfig {
+    /// The name of the server
+    pub name: Option<String>,
     /// Regular expression that the username must match
     pub user_name_regex: String,
     /// Regular expression that the password must match
@@ -1077,9 +1635,9 @@ pub struct GlobalInfoConfig {
 /// Simple server statistics
 pub struct GlobalInfo {
     /// The total number of posts
-    pub post_count: u32,
+    pub post_count: u64,
     /// Total disk usage
-    pub disk_usage: u32,
+    pub disk_usage: u64,
     /// The current featured post
     pub featured_post: Option<u32>,
     /// The time the current featured post was featured
@@ -1092,6 +1650,19 @@ pub struct GlobalInfo {
     pub config: GlobalInfoConfig,
 }
 
+impl GlobalInfo {
+    /// Whether a user of the given rank holds `privilege`, such as `posts:delete`, according to
+    /// the server's [privileges](GlobalInfoConfig::privileges). Privileges the server doesn't
+    /// list are treated as not held
+    pub fn can(&self, privilege: &str, rank: &UserRank) -> bool {
+        self.config
+            .privileges
+            .get(privilege)
+            .and_then(|required| rank_level(required))
+            .is_some_and(|required| rank.level() >= required)
+    }
+}
+
 #[cfg(feature = "python")]
 #[cfg_attr(all(feature = "python"), pymethods)]
 #[doc(hidden)]
@@ -1118,7 +1689,7 @@ pub struct PoolCategoryResource {
     /// The category color
     pub color: Option<String>,
     /// How many pools is the given category used with
-    pub usages: Option<u32>,
+    pub usages: Option<u64>,
     /// Whether the pool category is the default one
     pub default: Option<bool>,
 }
@@ -1185,12 +1756,46 @@ pub struct PoolResource {
     /// Time the pool was edited
     pub last_edit_time: Option<DateTime<Utc>>,
     /// The total number of posts the pool has
-    pub post_count: Option<u32>,
+    pub post_count: Option<u64>,
     /// The pool description (instructions how to use, history etc). The client should render
     /// it as Markdown
     pub description: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, AsRefStr, Eq, PartialEq)]
+#[strum(serialize_all = "camelCase")]
+/// A field of [PoolResource] that can be selected with
+/// [with_pool_fields](crate::SzurubooruRequest::with_pool_fields)
+pub enum PoolField {
+    /// Resource version
+    Version,
+    /// Pool ID
+    Id,
+    /// Pool names and aliases
+    Names,
+    /// Pool category name
+    Category,
+    /// Posts in the pool
+    Posts,
+    /// Time the pool was created
+    CreationTime,
+    /// Time the pool was last edited
+    LastEditTime,
+    /// Number of posts in the pool
+    PostCount,
+    /// Pool description
+    Description,
+}
+
+impl PoolResource {
+    /// The IDs of the pool's posts, in pool order. Empty if the pool was fetched without its
+    /// `posts`. Edit the list and send it back with
+    /// [update_pool](crate::SzurubooruRequest::update_pool) to reorder the pool
+    pub fn post_ids(&self) -> Vec<u32> {
+        self.posts.iter().flatten().map(|p| p.id).collect()
+    }
+}
+
 #[cfg(feature = "python")]
 #[cfg_attr(all(feature = "python"), pymethods)]
 #[doc(hidden)]
@@ -1266,6 +1871,9 @@ pub struct CreateUpdatePool {
 ///                     .build()
 ///                     .unwrap();
 /// ```
+///
+/// The builder's setters are named after the fields, as above. The API's own names, like
+/// [remove](MergePoolBuilder::remove), are accepted as aliases
 pub struct MergePool {
     /// Version of the pool to remove. Must match the current Pool version
     #[serde(rename = "removeVersion")]
@@ -1280,6 +1888,23 @@ pub struct MergePool {
     pub merge_to_pool: u32,
 }
 
+impl MergePoolBuilder {
+    /// Alias of [remove_pool_version](Self::remove_pool_version)
+    pub fn remove_version(&mut self, value: u32) -> &mut Self {
+        self.remove_pool_version(value)
+    }
+
+    /// Alias of [remove_pool](Self::remove_pool)
+    pub fn remove(&mut self, value: u32) -> &mut Self {
+        self.remove_pool(value)
+    }
+
+    /// Alias of [merge_to_pool](Self::merge_to_pool)
+    pub fn merge_to(&mut self, value: u32) -> &mut Self {
+        self.merge_to_pool(value)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(
     all(feature = "python"),
@@ -1295,7 +1920,7 @@ pub struct MicroPoolResource {
     /// The category this pool belongs to
     pub category: Option<String>,
     /// The total number of posts in this pool
-    pub post_count: Option<u32>,
+    pub post_count: Option<u64>,
     /// A markdown string describing the pool
     pub description: Option<String>,
 }
@@ -1338,6 +1963,31 @@ pub struct CommentResource {
     pub own_score: Option<i32>,
 }
 
+#[derive(Debug, Clone, Copy, AsRefStr, Eq, PartialEq)]
+#[strum(serialize_all = "camelCase")]
+/// A field of [CommentResource] that can be selected with
+/// [with_comment_fields](crate::SzurubooruRequest::with_comment_fields)
+pub enum CommentField {
+    /// Resource version
+    Version,
+    /// Comment ID
+    Id,
+    /// ID of the commented post
+    PostId,
+    /// Author of the comment
+    User,
+    /// Comment text
+    Text,
+    /// Time the comment was created
+    CreationTime,
+    /// Time the comment was last edited
+    LastEditTime,
+    /// Comment score
+    Score,
+    /// Score given by the authenticated user
+    OwnScore,
+}
+
 #[cfg(feature = "python")]
 #[cfg_attr(all(feature = "python"), pymethods)]
 #[doc(hidden)]
@@ -1410,6 +2060,7 @@ pub enum SnapshotResourceType {
     Tag,
     /// Tag category resource
     #[serde(rename = "tag_category")]
+    #[strum(serialize = "tag_category")]
     TagCategory,
     /// Post resource
     Post,
@@ -1417,6 +2068,7 @@ pub enum SnapshotResourceType {
     Pool,
     /// Pool Category
     #[serde(rename = "pool_category")]
+    #[strum(serialize = "pool_category")]
     PoolCategory,
 }
 
@@ -1509,6 +2161,158 @@ impl SnapshotModificationData {
     }
 }
 
+impl SnapshotModificationData {
+    /// Read the [value](SnapshotModificationData::value) as the diff of a
+    /// [tag](SnapshotResourceType::Tag) snapshot. Returns [None] if the value isn't a dictionary
+    pub fn as_tag_diff(&self) -> Option<TagSnapshotDiff> {
+        self.value.is_object().then(|| TagSnapshotDiff {
+            names: self.diff_field("names"),
+            category: self.diff_field("category"),
+            description: self.diff_field("description"),
+            implications: self.diff_field("implications"),
+            suggestions: self.diff_field("suggestions"),
+        })
+    }
+
+    /// Read the [value](SnapshotModificationData::value) as the diff of a
+    /// [post](SnapshotResourceType::Post) snapshot. Returns [None] if the value isn't a
+    /// dictionary
+    pub fn as_post_diff(&self) -> Option<PostSnapshotDiff> {
+        self.value.is_object().then(|| PostSnapshotDiff {
+            safety: self.diff_field("safety"),
+            source: self.diff_field("source"),
+            checksum: self.diff_field("checksum"),
+            featured: self.diff_field("featured"),
+            flags: self.diff_field("flags"),
+            tags: self.diff_field("tags"),
+            relations: self.diff_field("relations"),
+            notes: self.diff_field("notes"),
+        })
+    }
+
+    /// Read the [value](SnapshotModificationData::value) as the diff of a
+    /// [pool](SnapshotResourceType::Pool) snapshot. Returns [None] if the value isn't a
+    /// dictionary
+    pub fn as_pool_diff(&self) -> Option<PoolSnapshotDiff> {
+        self.value.is_object().then(|| PoolSnapshotDiff {
+            names: self.diff_field("names"),
+            category: self.diff_field("category"),
+            description: self.diff_field("description"),
+            posts: self.diff_field("posts"),
+        })
+    }
+
+    /// Read the [value](SnapshotModificationData::value) as the diff of a
+    /// [tag category](SnapshotResourceType::TagCategory) or
+    /// [pool category](SnapshotResourceType::PoolCategory) snapshot. Returns [None] if the value
+    /// isn't a dictionary
+    pub fn as_category_diff(&self) -> Option<CategorySnapshotDiff> {
+        self.value.is_object().then(|| CategorySnapshotDiff {
+            name: self.diff_field("name"),
+            color: self.diff_field("color"),
+            default: self.diff_field("default"),
+        })
+    }
+
+    /// Parse a single field of the diff, leaving it out if it's missing or has an unexpected
+    /// shape rather than failing the whole diff
+    fn diff_field<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        self.value
+            .get(key)
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+/// A `list change` in a snapshot diff
+pub struct SnapshotListChange<T> {
+    /// Items that were added to the list
+    #[serde(default)]
+    pub added: Vec<T>,
+    /// Items that were removed from the list
+    #[serde(default)]
+    pub removed: Vec<T>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+/// A `primitive change` in a snapshot diff
+pub struct SnapshotValueChange<T> {
+    /// The value before the change
+    pub old_value: T,
+    /// The value after the change
+    pub new_value: T,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+/// The changes recorded by a tag snapshot. See [SnapshotModificationData::as_tag_diff]
+///
+/// Fields are [None] when they didn't change
+pub struct TagSnapshotDiff {
+    /// Changes to the tag's names
+    pub names: Option<SnapshotListChange<String>>,
+    /// Change of the tag's category
+    pub category: Option<SnapshotValueChange<String>>,
+    /// Change of the tag's description
+    pub description: Option<SnapshotValueChange<Option<String>>>,
+    /// Changes to the tag's implications
+    pub implications: Option<SnapshotListChange<String>>,
+    /// Changes to the tag's suggestions
+    pub suggestions: Option<SnapshotListChange<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+/// The changes recorded by a post snapshot. See [SnapshotModificationData::as_post_diff]
+///
+/// Fields are [None] when they didn't change
+pub struct PostSnapshotDiff {
+    /// Change of the post's safety
+    pub safety: Option<SnapshotValueChange<PostSafety>>,
+    /// Change of the post's source
+    pub source: Option<SnapshotValueChange<Option<String>>>,
+    /// Change of the post's content checksum, when its content was replaced
+    pub checksum: Option<SnapshotValueChange<String>>,
+    /// Whether the post became or stopped being the featured post
+    pub featured: Option<SnapshotValueChange<bool>>,
+    /// Changes to the post's flags
+    pub flags: Option<SnapshotListChange<String>>,
+    /// Changes to the post's tags
+    pub tags: Option<SnapshotListChange<String>>,
+    /// Changes to the post's relations, by post ID
+    pub relations: Option<SnapshotListChange<u32>>,
+    /// Changes to the post's notes. Notes are left as raw JSON
+    pub notes: Option<SnapshotListChange<serde_json::Value>>,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+/// The changes recorded by a pool snapshot. See [SnapshotModificationData::as_pool_diff]
+///
+/// Fields are [None] when they didn't change
+pub struct PoolSnapshotDiff {
+    /// Changes to the pool's names
+    pub names: Option<SnapshotListChange<String>>,
+    /// Change of the pool's category
+    pub category: Option<SnapshotValueChange<String>>,
+    /// Change of the pool's description
+    pub description: Option<SnapshotValueChange<Option<String>>>,
+    /// Changes to the pool's posts, by post ID
+    pub posts: Option<SnapshotListChange<u32>>,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+/// The changes recorded by a tag or pool category snapshot.
+/// See [SnapshotModificationData::as_category_diff]
+///
+/// Fields are [None] when they didn't change
+pub struct CategorySnapshotDiff {
+    /// Change of the category's name
+    pub name: Option<SnapshotValueChange<String>>,
+    /// Change of the category's color
+    pub color: Option<SnapshotValueChange<String>>,
+    /// Whether the category became or stopped being the default
+    pub default: Option<SnapshotValueChange<bool>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
 #[cfg_attr(
     all(feature = "python"),
@@ -1518,10 +2322,12 @@ impl SnapshotModificationData {
 /// Type representing the data as part of a snapshot
 #[allow(clippy::large_enum_variant)]
 pub enum SnapshotData {
-    /// Data for a Created or Deleted resource
-    CreateOrDelete(SnapshotCreationDeletionData),
+    // Modify has to be tried first, every field of SnapshotCreationDeletionData is optional so
+    // it would match a diff as well
     /// Data for a modified resource
     Modify(SnapshotModificationData),
+    /// Data for a Created or Deleted resource
+    CreateOrDelete(SnapshotCreationDeletionData),
     /// Data for a merged resource
     Merge(Vec<String>),
 }
@@ -1550,7 +2356,8 @@ pub struct SnapshotResource {
     #[serde(rename = "type")]
     /// The resource type
     pub resource_type: Option<SnapshotResourceType>,
-    /// The ID of the snapshot itself
+    /// The ID of the changed resource (a tag's name, a post's number and so on). Snapshots don't
+    /// have IDs of their own
     pub id: Option<String>,
     /// The user who created this change
     pub user: Option<MicroUserResource>,
@@ -1656,9 +2463,18 @@ impl WithBaseURL for ImageSearchResult {
 /// A type that represents posts that are before or after an existing post
 pub struct AroundPostResult {
     /// A previous post, if it exists
-    prev: Option<u32>,
+    pub prev: Option<PostResource>,
     /// The next post, if it exists
-    next: Option<u32>,
+    pub next: Option<PostResource>,
+}
+
+impl WithBaseURL for AroundPostResult {
+    fn with_base_url(self, url: &str) -> Self {
+        AroundPostResult {
+            prev: self.prev.with_base_url(url),
+            next: self.next.with_base_url(url),
+        }
+    }
 }
 
 #[cfg(feature = "python")]
@@ -1673,10 +2489,19 @@ impl AroundPostResult {
 
 #[cfg(test)]
 mod tests {
+    use crate::errors::SzurubooruClientError;
     use crate::models::{
-        GlobalInfo, GlobalInfoConfig, PostResource, SnapshotResource, TagCategoryResource,
+        rank_level, AroundPostResult, CommentResource, CreateUpdatePostBuilder, GlobalInfo,
+        GlobalInfoConfig, MergePoolBuilder, MergePostBuilder, MergeTagsBuilder,
+        PoolCategoryResource, PoolResource, PostImportLine, PostResource, PostSafety, PostType,
+        RelationGraph, SnapshotData, SnapshotListChange, SnapshotModificationData,
+        SnapshotResource, SnapshotResourceType, SnapshotValueChange, SzuruEither,
+        TagCategoryResource, TagResource, UserAuthTokenResource, UserAvatarStyle, UserRank,
+        UserResource, WithBaseURL, ANONYMOUS_RANK_LEVEL,
     };
     use chrono::Datelike;
+    use serde::{de::DeserializeOwned, Serialize};
+    use serde_json::Value;
 
     #[test]
     fn test_parse_global_info() {
@@ -1705,9 +2530,9 @@ mod tests {
 
         let global_config =
             serde_json::from_str::<GlobalInfoConfig>(cfg_str).expect("Unable to parse cfg_str");
-        assert_eq!(global_config.can_send_mails, false);
+        assert!(!global_config.can_send_mails);
         let info_str = r#"{"postCount": 0,
-            "diskUsage": 0,
+            "diskUsage": 5368709120,
             "serverTime": "2024-08-09T21:41:24.123623Z",
             "config": {
                 "name": "integrationland",
@@ -1730,6 +2555,88 @@ mod tests {
         let global_info =
             serde_json::from_str::<GlobalInfo>(info_str).expect("Unable to parse info_str");
         assert_eq!(global_info.server_time.year(), 2024);
+        assert_eq!(global_info.disk_usage, 5_368_709_120);
+    }
+
+    #[test]
+    fn test_rank_levels() {
+        assert_eq!(rank_level("anonymous"), Some(ANONYMOUS_RANK_LEVEL));
+        assert_eq!(rank_level("power"), Some(UserRank::Power.level()));
+        assert!(UserRank::Regular.level() > rank_level("restricted").unwrap());
+        assert!(rank_level("nobody") > Some(UserRank::Administrator.level()));
+        assert_eq!(rank_level("superuser"), None);
+    }
+
+    #[test]
+    fn test_global_info_can() {
+        let info_str = r#"{"postCount": 0,
+            "diskUsage": 0,
+            "serverTime": "2024-08-09T21:41:24.123623Z",
+            "config": {
+                "name": "integrationland",
+                "userNameRegex": "^[a-zA-Z0-9_-]{1,32}$",
+                "passwordRegex": "^.{5,}$",
+                "tagNameRegex": "^\\S+$",
+                "tagCategoryNameRegex": "^[^\\s%+#/]+$",
+                "defaultUserRank": "regular",
+                "enableSafety": true,
+                "contactEmail": null,
+                "canSendMails": false,
+                "privileges": {
+                    "posts:delete": "moderator",
+                    "posts:list": "anonymous",
+                    "users:delete:any": "nobody"
+                }
+            }
+        }"#;
+        let info = serde_json::from_str::<GlobalInfo>(info_str).expect("Unable to parse info_str");
+        assert!(info.can("posts:delete", &UserRank::Moderator));
+        assert!(info.can("posts:delete", &UserRank::Administrator));
+        assert!(!info.can("posts:delete", &UserRank::Power));
+        assert!(info.can("posts:list", &UserRank::Restricted));
+        assert!(!info.can("users:delete:any", &UserRank::Administrator));
+        assert!(!info.can("posts:teleport", &UserRank::Administrator));
+
+        assert!(UserRank::Restricted < UserRank::Regular);
+        assert!(UserRank::Moderator >= UserRank::Power);
+    }
+
+    #[test]
+    fn test_pool_post_ids() {
+        let pool = serde_json::from_str::<PoolResource>(
+            r#"{"id": 1, "posts": [
+                {"id": 5, "thumbnailUrl": "data/generated-thumbnails/5.jpg"},
+                {"id": 2, "thumbnailUrl": "data/generated-thumbnails/2.jpg"}
+            ]}"#,
+        )
+        .expect("Unable to parse pool");
+        assert_eq!(pool.post_ids(), vec![5, 2]);
+
+        let pool = serde_json::from_str::<PoolResource>(r#"{"id": 1}"#).unwrap();
+        assert!(pool.post_ids().is_empty());
+    }
+
+    #[test]
+    fn test_user_rank_ordering() {
+        let mut ranks = vec![
+            UserRank::Administrator,
+            UserRank::Regular,
+            UserRank::Moderator,
+            UserRank::Restricted,
+            UserRank::Power,
+        ];
+        ranks.sort();
+        assert_eq!(
+            ranks,
+            vec![
+                UserRank::Restricted,
+                UserRank::Regular,
+                UserRank::Power,
+                UserRank::Moderator,
+                UserRank::Administrator,
+            ]
+        );
+        assert_eq!(ranks.iter().max(), Some(&UserRank::Administrator));
     }
 
     #[test]
@@ -1808,6 +2715,79 @@ mod tests {
         serde_json::from_str::<PostResource>(input_str).expect("Could not parse post resource");
     }
 
+    #[test]
+    fn test_post_diff() {
+        let old_str = r#"{
+            "safety": "safe",
+            "source": "https://example.com/cat.jpg",
+            "tags": [
+                {"names": ["cat"], "category": "default", "usages": 1},
+                {"names": ["maine_coon", "coon"], "category": "default", "usages": 1}
+            ],
+            "relations": [{"id": 2, "thumbnailUrl": "data/generated-thumbnails/2.jpg"}],
+            "notes": []
+        }"#;
+        let new_str = r#"{
+            "safety": "sketchy",
+            "source": "https://example.com/cat.jpg",
+            "tags": [
+                {"names": ["maine_coon"], "category": "default", "usages": 1},
+                {"names": ["kitten"], "category": "default", "usages": 1}
+            ],
+            "relations": [{"id": 3, "thumbnailUrl": "data/generated-thumbnails/3.jpg"}],
+            "notes": [{"polygon": [[0, 0], [0, 1], [1, 1]], "text": "whiskers"}]
+        }"#;
+        let old = serde_json::from_str::<PostResource>(old_str).expect("Could not parse old post");
+        let new = serde_json::from_str::<PostResource>(new_str).expect("Could not parse new post");
+
+        assert!(old.diff(&old).is_empty());
+        let diff = old.diff(&new);
+        assert_eq!(diff.tags_added, vec!["kitten".to_string()]);
+        assert_eq!(diff.tags_removed, vec!["cat".to_string()]);
+        assert!(diff.safety_changed);
+        assert!(!diff.source_changed);
+        assert_eq!(diff.relations_added, vec![3]);
+        assert_eq!(diff.relations_removed, vec![2]);
+        assert!(diff.notes_changed);
+    }
+
+    #[test]
+    fn test_relation_graph_neighbors() {
+        let graph = RelationGraph {
+            nodes: vec![1, 2, 3, 4],
+            edges: vec![(1, 2), (1, 3), (2, 4)],
+        };
+        assert_eq!(graph.neighbors(1).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(graph.neighbors(2).collect::<Vec<_>>(), vec![1, 4]);
+        assert_eq!(graph.neighbors(5).count(), 0);
+        assert!(graph.contains(4));
+        assert!(!graph.contains(5));
+    }
+
+    #[test]
+    fn test_post_create_template() {
+        let post_str = r#"{
+            "id": 7,
+            "version": 3,
+            "safety": "sketchy",
+            "source": "https://example.com/cat.jpg",
+            "contentUrl": "data/posts/7.jpg",
+            "tags": [{"names": ["maine_coon", "coon"], "category": "default", "usages": 1}],
+            "relations": [{"id": 2, "thumbnailUrl": "data/generated-thumbnails/2.jpg"}],
+            "flags": ["loop"]
+        }"#;
+        let post = serde_json::from_str::<PostResource>(post_str).expect("Could not parse post");
+        let template = post.as_create_template();
+        assert_eq!(template.version, None);
+        assert_eq!(template.tags, Some(vec!["maine_coon".to_string()]));
+        assert_eq!(template.safety, Some(PostSafety::Sketchy));
+        assert_eq!(template.source, post.source);
+        assert_eq!(template.relations, Some(vec![2]));
+        assert_eq!(template.notes, None);
+        assert_eq!(template.flags, Some(vec!["loop".to_string()]));
+        assert_eq!(template.content_url, None);
+    }
+
     #[test]
     fn test_parse_snapshot() {
         let input_str = r#"
@@ -1898,4 +2878,374 @@ mod tests {
         serde_json::from_str::<SnapshotResource>(input_str)
             .expect("Could not parse created snapshot resource");
     }
+
+    #[test]
+    fn test_snapshot_resource_type_tokens() {
+        for resource_type in [
+            SnapshotResourceType::Tag,
+            SnapshotResourceType::TagCategory,
+            SnapshotResourceType::PoolCategory,
+        ] {
+            let serialized = serde_json::to_value(&resource_type).unwrap();
+            assert_eq!(serialized.as_str(), Some(resource_type.as_ref()));
+        }
+    }
+
+    /// Parses a resource as the server sends it and checks that serializing it gives back the
+    /// same JSON, so every field the server sends is picked up under the right name
+    fn assert_round_trip<T: Serialize + DeserializeOwned>(input: &str) {
+        let expected: Value = serde_json::from_str(input).expect("Invalid test JSON");
+        let parsed: T = serde_json::from_value(expected.clone()).expect("Could not parse resource");
+        let serialized = serde_json::to_value(&parsed).expect("Could not serialize resource");
+        assert_eq!(serialized, expected);
+    }
+
+    const USER: &str = r#"{"name": "integration_user", "avatarUrl": "data/avatars/iu.png"}"#;
+    const TAG: &str = r#"{"names": ["cat", "kitty"], "category": "default", "usages": 3}"#;
+
+    #[test]
+    fn test_round_trip_tag_resources() {
+        assert_round_trip::<TagCategoryResource>(
+            r#"{"version": 1, "name": "default", "color": "default", "usages": 3, "order": 1,
+                "default": true}"#,
+        );
+        assert_round_trip::<TagResource>(&format!(
+            r#"{{"version": 2, "names": ["maine_coon"], "category": "default",
+                "implications": [{TAG}], "suggestions": [],
+                "creationTime": "2024-08-10T20:00:36.540774Z",
+                "lastEditTime": "2024-08-11T20:00:36.540774Z", "usages": 1,
+                "description": "A big cat"}}"#
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_post_resources() {
+        let comment = format!(
+            r#"{{"version": 1, "id": 1, "postId": 1, "user": {USER}, "text": "Nice",
+                "creationTime": "2024-08-10T20:00:36.540774Z", "lastEditTime": null,
+                "score": 1, "ownScore": 1}}"#
+        );
+        let pool = r#"{"version": 1, "id": 1, "names": ["cats"], "category": "default",
+            "posts": [{"id": 1, "thumbnailUrl": "data/generated-thumbnails/1.jpg"}],
+            "creationTime": "2024-08-10T20:00:36.540774Z", "lastEditTime": null,
+            "postCount": 1, "description": "Cats"}"#;
+        assert_round_trip::<CommentResource>(&comment);
+        assert_round_trip::<PoolResource>(pool);
+        assert_round_trip::<PoolCategoryResource>(
+            r#"{"version": 1, "name": "default", "color": "blue", "usages": 1, "default": true}"#,
+        );
+        assert_round_trip::<PostResource>(&format!(
+            r#"{{"version": 3, "id": 1, "creationTime": "2024-08-10T20:00:36.540774Z",
+                "lastEditTime": null, "safety": "safe", "type": "image",
+                "source": "https://example.com/cat.jpg",
+                "checksum": "1c0a8a30909183f4340081ae7c3b9b0d76fcfa8a",
+                "checksumMD5": "4e5915ba12d3e31ea63e8d1a4cda8ec7", "fileSize": 21555,
+                "canvasWidth": 225, "canvasHeight": 480, "contentUrl": "data/posts/1.jpg",
+                "thumbnailUrl": "data/generated-thumbnails/1.jpg", "flags": ["loop"],
+                "tags": [{TAG}],
+                "relations": [{{"id": 2, "thumbnailUrl": "data/generated-thumbnails/2.jpg"}}],
+                "notes": [{{"polygon": [[0.0, 0.0], [0.0, 0.5], [0.5, 0.5]], "text": "Ears"}}],
+                "user": {USER}, "score": 1, "ownScore": 1, "ownFavorite": true, "tagCount": 1,
+                "favoriteCount": 1, "commentCount": 1, "noteCount": 1, "featureCount": 0,
+                "relationCount": 1, "lastFeatureTime": null, "favoritedBy": [{USER}],
+                "hasCustomThumbnail": false, "mimeType": "image/jpeg",
+                "comments": [{comment}], "pools": [{pool}]}}"#
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_user_resources() {
+        assert_round_trip::<UserResource>(
+            r#"{"version": 1, "name": "integration_user", "email": "iu@example.com",
+                "rank": "administrator", "lastLoginTime": "2024-08-10T20:00:36.540774Z",
+                "creationTime": "2024-08-09T20:00:36.540774Z", "avatarStyle": "gravatar",
+                "avatarUrl": "data/avatars/iu.png", "commentCount": 2, "uploadedPostCount": 4,
+                "likedPostCount": 1, "dislikedPostCount": false, "favoritePostCount": 1}"#,
+        );
+        assert_round_trip::<UserAuthTokenResource>(&format!(
+            r#"{{"user": {USER}, "token": "c2a0e2a9-2d33-4e1e-a8a5-0a9e3b3c5d6f",
+                "note": "CI", "enabled": true, "expirationTime": "2025-08-10T20:00:36.540774Z",
+                "version": 1, "creationTime": "2024-08-10T20:00:36.540774Z",
+                "lastEditTime": null, "lastUsageTime": "2024-08-11T20:00:36.540774Z"}}"#
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_global_info() {
+        assert_round_trip::<GlobalInfo>(
+            r#"{"postCount": 4, "diskUsage": 5368709120, "featuredPost": 1,
+                "featuringTime": "2024-08-10T20:00:36.540774Z", "featuringUser": null,
+                "serverTime": "2024-08-11T20:00:36.540774Z",
+                "config": {"name": "integrationland", "userNameRegex": "^[a-zA-Z0-9_-]{1,32}$",
+                    "passwordRegex": "^.{5,}$", "tagNameRegex": "^\\S+$",
+                    "tagCategoryNameRegex": "^[^\\s%+#/]+$", "defaultUserRank": "regular",
+                    "enableSafety": true, "contactEmail": null, "canSendMails": false,
+                    "privileges": {"posts:create:identified": "regular"}}}"#,
+        );
+    }
+
+    #[test]
+    fn test_validate_for_create() {
+        let mut post = CreateUpdatePostBuilder::default()
+            .tags(vec!["cat".to_string()])
+            .build()
+            .unwrap();
+        assert!(matches!(
+            post.validate_for_create(),
+            Err(SzurubooruClientError::ValidationError(_))
+        ));
+        post.safety = Some(PostSafety::Safe);
+        assert!(post.validate_for_create().is_ok());
+    }
+
+    #[test]
+    fn test_validate_content_url() {
+        let mut post = CreateUpdatePostBuilder::default().build().unwrap();
+        assert!(post.validate_content_url().is_ok());
+
+        for bad in [
+            "file:///home/me/cat.jpg",
+            "/home/me/cat.jpg",
+            "ftp://example.com/cat.jpg",
+        ] {
+            post.content_url = Some(bad.to_string());
+            assert!(matches!(
+                post.validate_content_url(),
+                Err(SzurubooruClientError::ValidationError(_))
+            ));
+        }
+
+        for good in ["http://example.com/cat.jpg", "https://example.com/cat.jpg"] {
+            post.content_url = Some(good.to_string());
+            assert!(post.validate_content_url().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_parse_around_post() {
+        let around: AroundPostResult = serde_json::from_str(
+            r#"{
+              "prev": null,
+              "next": {
+                "id": 2,
+                "version": 1,
+                "contentUrl": "data/posts/2_aa2a1dc5c0efa5c3.jpg",
+                "thumbnailUrl": "data/generated-thumbnails/2_aa2a1dc5c0efa5c3.jpg"
+              }
+            }"#,
+        )
+        .unwrap();
+        assert!(around.prev.is_none());
+        let around = around.with_base_url("http://localhost:9801/");
+        let next = around.next.unwrap();
+        assert_eq!(next.id, Some(2));
+        assert_eq!(
+            next.content_url.as_deref(),
+            Some("http://localhost:9801/data/posts/2_aa2a1dc5c0efa5c3.jpg")
+        );
+    }
+
+    #[test]
+    fn test_parse_post_import_line() {
+        let line: PostImportLine = serde_json::from_str(
+            r#"{"tags": ["cat"], "safety": "sketchy", "contentPath": "export/1.jpg"}"#,
+        )
+        .unwrap();
+        assert_eq!(line.post.tags, Some(vec!["cat".to_string()]));
+        assert_eq!(line.post.safety, Some(PostSafety::Sketchy));
+        assert_eq!(line.post.content_url, None);
+        assert_eq!(line.content_path, Some("export/1.jpg".into()));
+    }
+
+    #[test]
+    fn test_post_type_aliases() {
+        let anim: PostType = serde_json::from_str(r#""anim""#).unwrap();
+        assert!(matches!(anim, PostType::Anim));
+        assert!(matches!(anim.normalized(), PostType::Animation));
+        assert_eq!(anim, PostType::Animated);
+        assert_eq!(PostType::Webm, PostType::Video);
+        assert_eq!(PostType::Swf.normalized(), PostType::Flash);
+        assert_ne!(PostType::Video, PostType::Animation);
+        assert_ne!(PostType::Image, PostType::Flash);
+    }
+
+    #[test]
+    fn test_user_permitted_fields() {
+        let user: UserResource = serde_json::from_str(
+            r#"{
+              "name": "someone_else",
+              "email": false,
+              "likedPostCount": false,
+              "dislikedPostCount": 3,
+              "favoritePostCount": 0
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(user.email_opt(), None);
+        assert_eq!(user.liked_post_count_opt(), None);
+        assert_eq!(user.disliked_post_count_opt(), Some(3));
+        assert_eq!(user.favorite_post_count_opt(), Some(0));
+
+        let user: UserResource =
+            serde_json::from_str(r#"{"name": "me", "email": "me@example.com"}"#).unwrap();
+        assert_eq!(user.email_opt(), Some("me@example.com"));
+        assert_eq!(user.liked_post_count_opt(), None);
+    }
+
+    #[test]
+    fn test_merge_builder_aliases() {
+        let canonical = MergeTagsBuilder::default()
+            .remove_tag_version(1)
+            .remove_tag("cat".to_string())
+            .merge_to_version(2)
+            .merge_to_tag("feline".to_string())
+            .build()
+            .unwrap();
+        let aliased = MergeTagsBuilder::default()
+            .remove_version(1)
+            .remove("cat".to_string())
+            .merge_to_version(2)
+            .merge_to("feline".to_string())
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&canonical).unwrap(),
+            serde_json::to_value(&aliased).unwrap()
+        );
+
+        let pool = MergePoolBuilder::default()
+            .remove_version(1)
+            .remove(1)
+            .merge_to_version(5)
+            .merge_to(3)
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&pool).unwrap(),
+            serde_json::json!({"removeVersion": 1, "remove": 1, "mergeToVersion": 5, "mergeTo": 3})
+        );
+
+        let post = MergePostBuilder::default()
+            .remove_version(1)
+            .remove(1)
+            .merge_to_version(2)
+            .merge_to(2)
+            .replace_content(true)
+            .build()
+            .unwrap();
+        assert!(post.replace_post_content);
+    }
+
+    #[test]
+    fn test_snapshot_typed_diffs() {
+        let tag_data: SnapshotData = serde_json::from_str(
+            r#"{
+                "type": "object change",
+                "value": {
+                    "names": {"type": "list change", "added": ["kitty"], "removed": ["cat"]},
+                    "category": {
+                        "type": "primitive change",
+                        "old-value": "default",
+                        "new-value": "species"
+                    },
+                    "description": {
+                        "type": "primitive change",
+                        "old-value": null,
+                        "new-value": "A small feline"
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let SnapshotData::Modify(modification) = tag_data else {
+            panic!("Expected a modification snapshot");
+        };
+        let diff = modification.as_tag_diff().unwrap();
+        assert_eq!(
+            diff.names,
+            Some(SnapshotListChange {
+                added: vec!["kitty".to_string()],
+                removed: vec!["cat".to_string()],
+            })
+        );
+        assert_eq!(diff.category.unwrap().new_value, "species");
+        assert_eq!(diff.description.unwrap().old_value, None);
+        assert!(diff.implications.is_none());
+
+        let modification = SnapshotModificationData {
+            snapshot_type: "object change".to_string(),
+            value: serde_json::json!({
+                "safety": {"type": "primitive change", "old-value": "safe", "new-value": "sketchy"},
+                "relations": {"type": "list change", "added": [3], "removed": []},
+                "tags": ["not", "a", "diff"]
+            }),
+        };
+        let diff = modification.as_post_diff().unwrap();
+        assert_eq!(
+            diff.safety,
+            Some(SnapshotValueChange {
+                old_value: PostSafety::Safe,
+                new_value: PostSafety::Sketchy,
+            })
+        );
+        assert_eq!(diff.relations.unwrap().added, vec![3]);
+        // An unexpected shape only drops that field
+        assert!(diff.tags.is_none());
+
+        let modification = SnapshotModificationData {
+            snapshot_type: "object change".to_string(),
+            value: Value::Null,
+        };
+        assert!(modification.as_pool_diff().is_none());
+    }
+
+    // UserResource and SnapshotModificationData declare some fields once per `python` cfg.
+    // Naming every field here means this only compiles if both variants agree, and the
+    // serialized keys catch a serde attribute that only made it onto one of the copies
+    #[test]
+    fn test_python_field_parity() {
+        let user = UserResource {
+            version: Some(1),
+            name: Some("integration_user".to_string()),
+            email: Some(SzuruEither::Right(false)),
+            rank: Some(UserRank::Regular),
+            last_login_time: None,
+            creation_time: None,
+            avatar_style: Some(UserAvatarStyle::Gravatar),
+            avatar_url: Some("data/avatars/integration_user.png".to_string()),
+            comment_count: Some(0),
+            uploaded_post_count: Some(0),
+            liked_post_count: Some(SzuruEither::Left(0)),
+            disliked_post_count: Some(SzuruEither::Left(0)),
+            favorite_post_count: Some(SzuruEither::Left(0)),
+        };
+        let user_json = serde_json::to_value(&user).unwrap();
+        let mut user_keys = user_json.as_object().unwrap().keys().collect::<Vec<_>>();
+        user_keys.sort();
+        assert_eq!(
+            user_keys,
+            vec![
+                "avatarStyle",
+                "avatarUrl",
+                "commentCount",
+                "creationTime",
+                "dislikedPostCount",
+                "email",
+                "favoritePostCount",
+                "lastLoginTime",
+                "likedPostCount",
+                "name",
+                "rank",
+                "uploadedPostCount",
+                "version",
+            ]
+        );
+
+        let modification = SnapshotModificationData {
+            snapshot_type: "object change".to_string(),
+            value: Value::Null,
+        };
+        let modification_json = serde_json::to_value(&modification).unwrap();
+        assert_eq!(modification_json["type"], "object change");
+    }
 }
@@ -19,7 +19,7 @@ pub struct PyPagedSearchResult {
     /// The maximum number of results to return
     pub limit: u32,
     /// The total number of results generated by the query
-    pub total: u32,
+    pub total: u64,
     /// The results themselves
     pub results: Py<PyList>,
 }
@@ -609,7 +609,7 @@ impl PythonSyncClient {
     ///
     /// :param int post_id: The ID of the post to fetch
     ///
-    /// :return: A resource containing the IDs of the next and previous IDs
+    /// :return: A resource containing the previous and next posts
     /// :rtype: :class:`~szurubooru_client.models.AroundPostResult`
     pub fn get_around_post(&self, post_id: u32) -> PyResult<AroundPostResult> {
         self.runtime.block_on(self.client.get_around_post(post_id))
@@ -62,6 +62,37 @@ impl QueryToken {
         }
     }
 
+    ///
+    /// Constructs a named token matching any of the given values, e.g. `tag:cat,dog`. Values are
+    /// escaped like in [QueryToken::token], and commas inside a value are escaped so that they
+    /// aren't read as separators.
+    ///
+    /// One `any_of` token is much cheaper than running a query per value.
+    /// ```no_run
+    /// # use szurubooru_client::SzurubooruClient;
+    /// # let client = SzurubooruClient::new_with_token("http://foo", "user", "pwd", true).unwrap();
+    /// // let client = SzurubooruClient::new(...)
+    /// use szurubooru_client::tokens::{PostNamedToken, QueryToken};
+    /// // Posts tagged with either "cat" or "dog"
+    /// let pets = QueryToken::any_of(PostNamedToken::Tag, &["cat", "dog"]);
+    /// client.request().list_posts(Some(&vec![pets]));
+    /// ```
+    pub fn any_of(key: impl AsRef<str>, values: &[impl AsRef<str>]) -> Self {
+        let escaped = values
+            .iter()
+            .map(|v| {
+                v.as_ref()
+                    .replace(":", "\\:")
+                    .replace("-", "\\-")
+                    .replace(",", "\\,")
+            })
+            .collect::<Vec<_>>();
+        Self {
+            key: key.as_ref().to_string(),
+            value: escaped.join(","),
+        }
+    }
+
     ///
     /// Constructs a token for sorting purposes. Final results take the form of
     /// `sort:value`.
@@ -85,6 +116,25 @@ impl QueryToken {
         }
     }
 
+    ///
+    /// Constructs a sort token with an explicit direction. Final results take the form of
+    /// `sort:value,asc` or `sort:value,desc`.
+    ///
+    /// Plain [QueryToken::sort] leaves the order up to the server, which is descending for most
+    /// fields.
+    /// ```no_run
+    /// # use szurubooru_client::SzurubooruClient;
+    /// # let client = SzurubooruClient::new_with_token("http://foo", "user", "pwd", true).unwrap();
+    /// // let client = SzurubooruClient::new(...)
+    /// use szurubooru_client::tokens::{PostSortToken, QueryToken, SortDirection};
+    /// // Newest posts first
+    /// let newest = QueryToken::sort_by(PostSortToken::CreationDate, SortDirection::Desc);
+    /// client.request().list_posts(Some(&vec![newest]));
+    /// ```
+    pub fn sort_by(value: impl AsRef<str>, direction: SortDirection) -> Self {
+        QueryToken::sort(format!("{},{}", value.as_ref(), direction.as_ref()))
+    }
+
     ///
     /// Constructs a new anonymous token. These are resource specific, e.g for [crate::models::PostResource] it's
     /// the same as [PostNamedToken::Tag].
@@ -125,6 +175,112 @@ impl QueryToken {
         QueryToken::anonymous(key)
     }
 
+    ///
+    /// Constructs a negated anonymous token, matching resources that do *not* have `key`.
+    /// The same as `QueryToken::anonymous(key).negate()`.
+    /// ```no_run
+    /// # use szurubooru_client::SzurubooruClient;
+    /// # let client = SzurubooruClient::new_with_token("http://foo", "user", "pwd", true).unwrap();
+    /// // let client = SzurubooruClient::new(...)
+    /// use szurubooru_client::tokens::QueryToken;
+    /// // Fetch all posts tagged "cat" but not "dog", i.e. `cat -dog`
+    /// let query = vec![QueryToken::anonymous("cat"), QueryToken::negated("dog")];
+    /// client.request().list_posts(Some(&query));
+    /// ```
+    pub fn negated(key: impl AsRef<str>) -> Self {
+        QueryToken::anonymous(key).negate()
+    }
+
+    ///
+    /// Constructs a negated named token, e.g. `-safety:unsafe`.
+    /// The same as `QueryToken::token(key, value).negate()`.
+    pub fn negated_token(key: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        QueryToken::token(key, value).negate()
+    }
+
+    ///
+    /// Constructs a named token matching an inclusive range of values. Final results take the
+    /// form of `key:min..max`, `key:min..` or `key:..max` depending on which bounds are given.
+    /// At least one bound should be set.
+    ///
+    /// Works with any numeric or date [NamedToken], such as [PostNamedToken::Score],
+    /// [PostNamedToken::FileSize] or [PostNamedToken::CreationDate].
+    /// ```no_run
+    /// # use szurubooru_client::SzurubooruClient;
+    /// # let client = SzurubooruClient::new_with_token("http://foo", "user", "pwd", true).unwrap();
+    /// // let client = SzurubooruClient::new(...)
+    /// use szurubooru_client::tokens::{PostNamedToken, QueryToken};
+    /// // Posts scored between 10 and 20 that were uploaded in 2020 or later
+    /// let score = QueryToken::range(PostNamedToken::Score, Some(10), Some(20));
+    /// let date = QueryToken::range(PostNamedToken::CreationDate, Some(2020), None);
+    /// client.request().list_posts(Some(&vec![score, date]));
+    /// ```
+    pub fn range<T: Display>(key: impl AsRef<str>, min: Option<T>, max: Option<T>) -> Self {
+        let bound = |b: Option<T>| b.map(|b| b.to_string()).unwrap_or_default();
+        QueryToken::token(key, format!("{}..{}", bound(min), bound(max)))
+    }
+
+    ///
+    /// Constructs a named token comparing against a single value. The server has no `>` or `<`
+    /// operators, so these are sent as open-ended ranges: [Comparison::AtLeast] becomes
+    /// `key:value..` and [Comparison::AtMost] becomes `key:..value`.
+    /// ```no_run
+    /// # use szurubooru_client::SzurubooruClient;
+    /// # let client = SzurubooruClient::new_with_token("http://foo", "user", "pwd", true).unwrap();
+    /// // let client = SzurubooruClient::new(...)
+    /// use szurubooru_client::tokens::{Comparison, PostNamedToken, QueryToken};
+    /// // Posts no larger than 1 MB
+    /// let small = QueryToken::comparison(PostNamedToken::FileSize, Comparison::AtMost, 1_000_000);
+    /// client.request().list_posts(Some(&vec![small]));
+    /// ```
+    pub fn comparison<T: Display>(key: impl AsRef<str>, comparison: Comparison, value: T) -> Self {
+        match comparison {
+            Comparison::AtLeast => QueryToken::range(key, Some(value), None),
+            Comparison::AtMost => QueryToken::range(key, None, Some(value)),
+            Comparison::Exactly => QueryToken::token(key, value.to_string()),
+        }
+    }
+
+    ///
+    /// Splits a raw query string, such as one typed into the web UI, into tokens. Terms are
+    /// separated by whitespace and split into key and value at the first unescaped `:`; terms
+    /// without one become anonymous tokens, and a leading `-` negates the term.
+    ///
+    /// Escapes are kept as-is, so [to_query_string](ToQueryString::to_query_string) gives back
+    /// the same query.
+    /// ```
+    /// use szurubooru_client::tokens::{QueryToken, ToQueryString};
+    /// let tokens = QueryToken::parse(r"re\:zero -safety:unsafe sort:score");
+    /// assert_eq!(tokens[0].key, r"re\:zero");
+    /// assert_eq!(tokens[1].key, "-safety");
+    /// assert_eq!(tokens.to_query_string(), r"re\:zero -safety:unsafe sort:score");
+    /// ```
+    pub fn parse(query: &str) -> Vec<Self> {
+        query
+            .split_whitespace()
+            .map(|term| {
+                let mut escaped = false;
+                let separator = term.char_indices().find_map(|(i, c)| match c {
+                    ':' if !escaped => Some(i),
+                    _ => {
+                        escaped = !escaped && c == '\\';
+                        None
+                    }
+                });
+                match separator {
+                    Some(i) => Self {
+                        key: term[..i].to_string(),
+                        value: term[i + 1..].to_string(),
+                    },
+                    None => Self {
+                        key: term.to_string(),
+                        value: String::new(),
+                    },
+                }
+            })
+            .collect()
+    }
+
     ///
     /// Negate the existing token. Include becomes Exclude and vice versa.
     ///
@@ -376,6 +532,34 @@ impl ToQueryString for Vec<QueryToken> {
     }
 }
 
+impl ToQueryString for [QueryToken] {
+    fn to_query_string(&self) -> String {
+        let query_vec: Vec<String> = self.iter().map(|qv| qv.to_string()).collect();
+        query_vec.join(" ")
+    }
+}
+
+/// How [QueryToken::comparison] compares a field against its value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    /// Greater than or equal to the value
+    AtLeast,
+    /// Less than or equal to the value
+    AtMost,
+    /// Equal to the value
+    Exactly,
+}
+
+/// The order requested by [QueryToken::sort_by]
+#[derive(Debug, AsRefStr, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "lowercase")]
+pub enum SortDirection {
+    /// Smallest or oldest values first
+    Asc,
+    /// Largest or newest values first
+    Desc,
+}
+
 #[derive(Debug, AsRefStr, PartialEq, Eq, Clone)]
 #[strum(serialize_all = "kebab-case")]
 #[cfg_attr(
@@ -684,6 +868,8 @@ pub enum PoolNamedToken {
     EditTime,
     /// used in given number of posts
     PostCount,
+    /// having given pool number
+    Id,
 }
 impl NamedToken for PoolNamedToken {}
 
@@ -885,6 +1071,47 @@ mod tests {
         assert_eq!(qt.to_string(), "foo");
     }
 
+    #[test]
+    fn test_parse_query() {
+        let query = r#"cat -dog  safety:safe -uploader:some\-user re\:zero sort:score,asc"#;
+        let tokens = QueryToken::parse(query);
+        let pairs = tokens
+            .iter()
+            .map(|t| (t.key.as_str(), t.value.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pairs,
+            vec![
+                ("cat", ""),
+                ("-dog", ""),
+                ("safety", "safe"),
+                ("-uploader", r#"some\-user"#),
+                (r#"re\:zero"#, ""),
+                ("sort", "score,asc"),
+            ]
+        );
+        assert_eq!(
+            tokens.to_query_string(),
+            r#"cat -dog safety:safe -uploader:some\-user re\:zero sort:score,asc"#
+        );
+        assert!(QueryToken::parse("   ").is_empty());
+    }
+
+    #[test]
+    fn test_any_of() {
+        let qt = QueryToken::any_of(PostNamedToken::Tag, &["cat", "re:zero", "a,b"]);
+        assert_eq!(qt.to_string(), r#"tag:cat,re\:zero,a\,b"#);
+    }
+
+    #[test]
+    fn test_sort_direction() {
+        let qt = QueryToken::sort_by(PostSortToken::CreationDate, SortDirection::Desc);
+        assert_eq!(qt.to_string(), "sort:creation-date,desc");
+
+        let qt = QueryToken::sort_by(TagSortToken::Name, SortDirection::Asc);
+        assert_eq!(qt.to_string(), "sort:name,asc");
+    }
+
     #[test]
     fn test_vec_query() {
         let query_vec = vec![
@@ -894,4 +1121,44 @@ mod tests {
 
         assert_eq!(query_vec.to_query_string(), "comment-count:1 sort:random");
     }
+
+    #[test]
+    fn test_negated_query() {
+        let query_vec = vec![
+            QueryToken::anonymous("cat"),
+            QueryToken::negated("dog"),
+            QueryToken::negated_token(PostNamedToken::Safety, "unsafe"),
+            QueryToken::negated("re:zero"),
+        ];
+
+        assert_eq!(
+            query_vec.to_query_string(),
+            r#"cat -dog -safety:unsafe -re\:zero"#
+        );
+        assert_eq!(QueryToken::negated("dog").negate().to_string(), "dog");
+    }
+
+    #[test]
+    fn test_range_query() {
+        let qt = QueryToken::range(PostNamedToken::Score, Some(10), Some(20));
+        assert_eq!(qt.to_string(), "score:10..20");
+
+        let qt = QueryToken::range(PostNamedToken::Score, Some(10), None);
+        assert_eq!(qt.to_string(), "score:10..");
+
+        let qt = QueryToken::range(PostNamedToken::FileSize, None, Some(1024));
+        assert_eq!(qt.to_string(), "file-size:..1024");
+
+        let qt = QueryToken::range(PostNamedToken::CreationDate, Some("2020"), Some("2021"));
+        assert_eq!(qt.to_string(), "creation-date:2020..2021");
+
+        let qt = QueryToken::comparison(PostNamedToken::Score, Comparison::AtLeast, 5);
+        assert_eq!(qt.to_string(), "score:5..");
+
+        let qt = QueryToken::comparison(PostNamedToken::Score, Comparison::AtMost, -5);
+        assert_eq!(qt.to_string(), r#"score:..\-5"#);
+
+        let qt = QueryToken::comparison(PostNamedToken::Score, Comparison::Exactly, 3);
+        assert_eq!(qt.to_string(), "score:3");
+    }
 }
@@ -1,4 +1,5 @@
 use chrono::Months;
+use futures_util::TryStreamExt;
 use sha1::{Digest, Sha1};
 use std::error::Error;
 use std::fs::File;
@@ -6,8 +7,9 @@ use std::io::{Seek, SeekFrom};
 use std::path::Path;
 use std::process::Stdio;
 use std::time::Duration;
+use szurubooru_client::errors::SzurubooruClientError;
 use szurubooru_client::models::*;
-use szurubooru_client::tokens::{CommentNamedToken, QueryToken};
+use szurubooru_client::tokens::{CommentNamedToken, Comparison, PostNamedToken, QueryToken};
 use szurubooru_client::*;
 use tempfile::tempfile;
 use tokio::process::Command;
@@ -219,6 +221,14 @@ async fn test_tags(client: &SzurubooruClient) {
         .expect("Could not list tags");
     assert_eq!(tag_list.total, 1);
     assert!(tag_list.results.first().unwrap().description.is_none());
+    let tag_list = client
+        .with_tag_fields(&[TagField::Version, TagField::Names, TagField::Usages])
+        .list_tags(None)
+        .await
+        .expect("Could not list tags with typed fields");
+    let tag = tag_list.results.first().unwrap();
+    assert!(tag.usages.is_some());
+    assert!(tag.category.is_none());
 
     info!("Updating tag");
     let utag = CreateUpdateTagBuilder::default()
@@ -260,6 +270,15 @@ async fn test_tags(client: &SzurubooruClient) {
         .expect("Could not list tags");
     assert_eq!(tag_list.total, 2);
 
+    info!("Listing tag category facets");
+    let facets = client
+        .request()
+        .tag_category_facets()
+        .await
+        .expect("Could not list tag category facets");
+    assert_eq!(facets.len(), 1);
+    assert_eq!(facets[0].1, 2);
+
     info!("Merging tags");
     let merge_tag = MergeTagsBuilder::default()
         .remove_tag_version(bar_tag.version)
@@ -281,6 +300,66 @@ async fn test_tags(client: &SzurubooruClient) {
         .expect("Could not list tags");
     assert_eq!(tag_list.total, 1);
 
+    info!("Merging tags by name");
+    let cutag = CreateUpdateTagBuilder::default()
+        .names(vec!["baz".to_string()])
+        .category("default".to_string())
+        .build()
+        .expect("Could not build the CreateUpdateTag");
+    client
+        .request()
+        .create_tag(&cutag)
+        .await
+        .expect("Could not create tag");
+    let merged_tag = client
+        .request()
+        .merge_tags_by_name("baz", "foo")
+        .await
+        .expect("Could not merge tags by name");
+    assert_eq!(tag_res3.names, merged_tag.names);
+
+    info!("Creating tags in bulk");
+    let bulk_tags = ["qux", "foo", "quux"]
+        .into_iter()
+        .map(|name| {
+            CreateUpdateTagBuilder::default()
+                .names(vec![name.to_string()])
+                .category("default".to_string())
+                .build()
+                .expect("Could not build the CreateUpdateTag")
+        })
+        .collect::<Vec<_>>();
+    let bulk_results = client.request().create_tags(&bulk_tags).await;
+    assert_eq!(bulk_results.len(), 3);
+    assert!(
+        bulk_results[1].is_err(),
+        "Expected the duplicate tag to fail"
+    );
+    for (name, result) in [("qux", &bulk_results[0]), ("quux", &bulk_results[2])] {
+        let tag = result.as_ref().expect("Could not create tag in bulk");
+        assert_eq!(tag.names, Some(vec![name.to_string()]));
+        client
+            .request()
+            .delete_tag(name, tag.version)
+            .await
+            .expect("Could not delete bulk-created tag");
+    }
+
+    info!("Suggesting tags by prefix");
+    let suggestions = client
+        .request()
+        .suggest_tags("fo", 5)
+        .await
+        .expect("Could not suggest tags");
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(suggestions[0].names, tag_res3.names);
+    let suggestions = client
+        .request()
+        .suggest_tags("zzz", 5)
+        .await
+        .expect("Could not suggest tags");
+    assert!(suggestions.is_empty());
+
     info!("Deleting tag");
     client
         .request()
@@ -326,6 +405,45 @@ async fn test_creating_posts(client: &SzurubooruClient) {
         .update_post(wiki_post.id.unwrap(), &wiki_post_update)
         .await
         .expect("Unable to up wiki post object");
+
+    info!("Updating existing post with a stale version");
+    let stale_update = CreateUpdatePostBuilder::default()
+        .version(wiki_post.version.unwrap() - 1)
+        .source("Stale".to_string())
+        .build()
+        .expect("Could not build stale wiki post update object");
+    let stale_err = client
+        .request()
+        .update_post(wiki_post.id.unwrap(), &stale_update)
+        .await
+        .expect_err("Expected a stale update to fail");
+    assert!(matches!(
+        stale_err,
+        SzurubooruClientError::VersionConflict(_)
+    ));
+    let wiki_post = client
+        .request()
+        .update_post_retry_conflict(wiki_post.id.unwrap(), &stale_update)
+        .await
+        .expect("Unable to update wiki post after refreshing its version");
+    assert_eq!(wiki_post.source.as_deref(), Some("Stale"));
+
+    info!("Patching existing post with a stale version");
+    let wiki_patch = PostPatch {
+        source: Some("Wikimedia Commons".to_string()),
+        flags: Some(vec![]),
+        ..Default::default()
+    };
+    let wiki_post = client
+        .request()
+        .patch_post(
+            wiki_post.id.unwrap(),
+            wiki_post.version.unwrap() - 1,
+            &wiki_patch,
+        )
+        .await
+        .expect("Unable to patch wiki post");
+    assert_eq!(wiki_post.source.as_deref(), Some("Wikimedia Commons"));
     let post_list = client
         .request()
         .list_posts(None)
@@ -340,6 +458,27 @@ async fn test_creating_posts(client: &SzurubooruClient) {
         .await
         .expect("Could not delete wiki post");
 
+    info!("Test streaming upload by file path");
+    let folly_obj = CreateUpdatePostBuilder::default()
+        .safety(PostSafety::Safe)
+        .build()
+        .expect("Could not build streaming upload object");
+    let folly_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../folly.jpg");
+    let folly_post = client
+        .request()
+        .create_post_from_file_path_streaming(&folly_path, None::<&Path>, &folly_obj)
+        .await
+        .expect("Could not create post from streamed folly file");
+    assert_eq!(
+        folly_post.checksum.as_deref(),
+        Some("a827533744820bb022b8ea8d8333aa6ca8851bd0")
+    );
+    client
+        .request()
+        .delete_post(folly_post.id.unwrap(), folly_post.version.unwrap())
+        .await
+        .expect("Could not delete streamed post");
+
     info!("Test upload by File type");
     let folly1_obj = CreateUpdatePostBuilder::default()
         .tags(vec![
@@ -352,8 +491,8 @@ async fn test_creating_posts(client: &SzurubooruClient) {
         .expect("Could not build first upload object");
     let folly1_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../folly1.jpg");
     let mut folly1_file =
-        File::open(&folly1_path).expect(&format!("Could not open file {folly1_path:?}"));
-    let _folly1_post = client
+        File::open(&folly1_path).unwrap_or_else(|_| panic!("Could not open file {folly1_path:?}"));
+    let folly1_post = client
         .request()
         .create_post_from_file(&mut folly1_file, None, "folly1.jpg", &folly1_obj)
         .await
@@ -410,6 +549,38 @@ async fn test_creating_posts(client: &SzurubooruClient) {
         .expect("Could not reverse image search");
     assert!(matching_posts.exact_post.is_some());
 
+    info!("Searching by image bytes");
+    let folly3_bytes = std::fs::read(&folly3_path).expect("Could not read folly3.jpg");
+    let exact_match = client
+        .request()
+        .post_for_bytes(&folly3_bytes)
+        .await
+        .expect("Could not search for post by bytes");
+    assert_eq!(exact_match.and_then(|p| p.id), folly3_post.id);
+    let folly3_query = vec![QueryToken::token(PostNamedToken::Tag, "folly3")];
+    let md5_match = client
+        .request()
+        .scan_posts_for_md5(
+            Some(&folly3_query),
+            &folly3_post.checksum_md5.clone().unwrap().to_uppercase(),
+            10,
+        )
+        .await
+        .expect("Could not search for post by MD5");
+    assert_eq!(md5_match.and_then(|p| p.id), folly3_post.id);
+    let no_match = client
+        .request()
+        .scan_posts_for_md5(Some(&folly3_query), "00000000000000000000000000000000", 10)
+        .await
+        .expect("Could not search for missing post by MD5");
+    assert!(no_match.is_none());
+    let bytes_matches = client
+        .request()
+        .reverse_search_bytes(folly3_bytes, "folly3.jpg")
+        .await
+        .expect("Could not reverse image search from bytes");
+    assert_eq!(bytes_matches.exact_post.and_then(|p| p.id), folly3_post.id);
+
     info!("Testing temporary upload");
     let folly4_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../folly4.jpg");
     let folly4_temp_upload = client
@@ -441,6 +612,150 @@ async fn test_creating_posts(client: &SzurubooruClient) {
         .expect("Could not list posts by tag cat");
     assert_eq!(f4_results.total, 4);
 
+    info!("Navigating around a post");
+    let around = client
+        .request()
+        .get_around_post(folly1_post.id.unwrap())
+        .await
+        .expect("Could not get posts around folly1");
+    let neighbor = around
+        .prev
+        .or(around.next)
+        .expect("Expected folly1 to have a neighbor");
+    assert!(neighbor.content_url.unwrap().starts_with("http"));
+    let around = client
+        .request()
+        .get_around_post_with_query(
+            folly1_post.id.unwrap(),
+            Some(&vec![QueryToken::anonymous("folly1")]),
+        )
+        .await
+        .expect("Could not get posts around folly1 within a query");
+    assert!(around.prev.is_none() && around.next.is_none());
+
+    info!("Counting posts by tag");
+    let cat_count = client
+        .request()
+        .count_posts(Some(&vec![QueryToken::anonymous("cat")]))
+        .await
+        .expect("Could not count posts by tag cat");
+    assert_eq!(cat_count, 4);
+    let sized_count = client
+        .request()
+        .count_posts(Some(&vec![
+            QueryToken::anonymous("cat"),
+            QueryToken::range(PostNamedToken::FileSize, Some(1), None),
+        ]))
+        .await
+        .expect("Could not count posts by file size range");
+    assert_eq!(sized_count, 4);
+    let tiny_count = client
+        .request()
+        .count_posts(Some(&vec![QueryToken::comparison(
+            PostNamedToken::FileSize,
+            Comparison::AtMost,
+            1,
+        )]))
+        .await
+        .expect("Could not count posts by file size comparison");
+    assert_eq!(tiny_count, 0);
+
+    info!("Listing user uploads");
+    let all_count = client
+        .request()
+        .count_posts(None)
+        .await
+        .expect("Could not count all posts");
+    let upload_count = client
+        .request()
+        .count_user_uploads("integration_user")
+        .await
+        .expect("Could not count user uploads");
+    assert_eq!(upload_count, all_count);
+    let uploads = client
+        .request()
+        .with_limit(2)
+        .list_user_uploads("integration_user")
+        .await
+        .expect("Could not list user uploads");
+    assert_eq!(uploads.total, all_count);
+    assert_eq!(uploads.results.len(), 2);
+
+    info!("Importing posts from a JSONL manifest");
+    let manifest = format!(
+        "{}\n\nnot json\n{}\n",
+        r#"{"tags": ["cat"], "safety": "safe", "contentPath": "FOLLY2"}"#.replace(
+            "FOLLY2",
+            &Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../folly2.jpg")
+                .to_string_lossy()
+        ),
+        r#"{"tags": ["cat"], "safety": "safe"}"#,
+    );
+    let import_results = client
+        .request()
+        .import_posts_jsonl(manifest.as_bytes(), 2)
+        .await;
+    assert_eq!(import_results.len(), 3);
+    assert!(matches!(
+        import_results[0].result,
+        Ok(PostImportOutcome::AlreadyUploaded)
+    ));
+    assert_eq!(import_results[1].line_number, 3);
+    assert!(import_results[1].result.is_err());
+    assert!(import_results[2].result.is_err());
+
+    info!("Listing posts created after folly1");
+    let newer_posts = client
+        .request()
+        .posts_created_after(folly1_post.id.unwrap(), 100)
+        .await
+        .expect("Could not list posts created after folly1");
+    assert_eq!(newer_posts.len(), 3);
+
+    info!("Grouping posts by tag category");
+    let groups = client
+        .request()
+        .group_posts_by_category(Some(&vec![QueryToken::anonymous("cat")]), "default")
+        .await
+        .expect("Could not group posts by category");
+    assert_eq!(groups.get("cat").map(Vec::len), Some(4));
+    assert_eq!(groups.get("folly1").map(Vec::len), Some(1));
+
+    info!("Suggesting relations");
+    let suggested = client
+        .request()
+        .suggest_relations(folly1_post.id.unwrap(), 2, 1000)
+        .await
+        .expect("Could not suggest relations");
+    let mut other_cats = groups["cat"]
+        .iter()
+        .filter_map(|p| p.id)
+        .filter(|id| Some(*id) != folly1_post.id)
+        .collect::<Vec<_>>();
+    other_cats.sort();
+    assert_eq!(suggested, other_cats);
+    let suggested = client
+        .request()
+        .suggest_relations(folly1_post.id.unwrap(), 3, 1000)
+        .await
+        .expect("Could not suggest relations");
+    assert!(suggested.is_empty());
+
+    info!("Listing under-tagged posts");
+    let untagged = client
+        .request()
+        .list_untagged_posts()
+        .await
+        .expect("Could not list untagged posts");
+    assert_eq!(untagged.total, 0);
+    let missing_default = client
+        .request()
+        .list_posts_missing_category("default")
+        .await
+        .expect("Could not list posts missing a default tag");
+    assert_eq!(missing_default.total, 0);
+
     info!("Testing pagination");
     let post_list = client
         .request()
@@ -458,6 +773,62 @@ async fn test_creating_posts(client: &SzurubooruClient) {
         .expect("Could not list posts page 2");
     assert_ne!(post_list.results, post_list2.results);
 
+    info!("Testing raw server URLs");
+    let raw_posts = client
+        .request()
+        .with_limit(1)
+        .without_url_propagation()
+        .list_posts(None)
+        .await
+        .expect("Could not list posts without URL propagation");
+    let raw_url = raw_posts.results[0]
+        .content_url
+        .as_ref()
+        .expect("Post has no content URL");
+    assert!(!raw_url.starts_with("http"));
+    assert!(post_list.results[0]
+        .content_url
+        .as_ref()
+        .is_some_and(|u| u.starts_with("http")));
+
+    info!("Testing page numbers");
+    let page2 = client
+        .request()
+        .with_limit(3)
+        .page_posts(None, 2)
+        .await
+        .expect("Could not fetch page 2");
+    assert_eq!(page2.results.len(), 1);
+    let last_page = client
+        .request()
+        .with_limit(3)
+        .last_page_posts(None)
+        .await
+        .expect("Could not fetch the last page");
+    assert_eq!(last_page.results, page2.results);
+    let past_end = client
+        .request()
+        .with_limit(3)
+        .page_posts(None, 5)
+        .await
+        .expect("Could not fetch a page past the end");
+    assert!(past_end.results.is_empty());
+
+    info!("Streaming every post");
+    let small_pages = client.with_limit(2);
+    let all_posts = small_pages
+        .list_all_posts(None)
+        .try_collect::<Vec<_>>()
+        .await
+        .expect("Could not stream all posts");
+    assert_eq!(all_posts.len() as u64, post_list.total);
+    let all_cats = small_pages
+        .list_all_posts(Some(&vec![QueryToken::anonymous("cat")]))
+        .try_collect::<Vec<_>>()
+        .await
+        .expect("Could not stream all cat posts");
+    assert_eq!(all_cats.len(), 4);
+
     info!("Testing tag siblings");
     let tag_occurrences = client
         .request()
@@ -493,6 +864,25 @@ async fn test_creating_posts(client: &SzurubooruClient) {
         .expect("Could not favorite post");
     assert_eq!(folly3_post.own_favorite, Some(true));
 
+    info!("Getting post interaction state");
+    let interaction = client
+        .request()
+        .get_post_interaction_state(folly3_post.id.unwrap())
+        .await
+        .expect("Could not get post interaction state");
+    assert!(interaction.own_favorite);
+    assert_eq!(interaction.own_score, 1);
+    assert_eq!(interaction.favorite_count, 1);
+
+    info!("Listing favorites");
+    let favorites = client
+        .request()
+        .list_favorites("integration_user")
+        .await
+        .expect("Could not list favorites");
+    assert_eq!(favorites.total, 1);
+    assert_eq!(favorites.results[0].id, folly3_post.id);
+
     info!("Unfavorite post");
     let folly3_post = client
         .request()
@@ -501,6 +891,48 @@ async fn test_creating_posts(client: &SzurubooruClient) {
         .expect("Could not unfavorite post");
     assert_eq!(folly3_post.own_favorite, Some(false));
 
+    info!("Liking post");
+    let liked_post = client
+        .request()
+        .like_post(folly3_post.id.unwrap())
+        .await
+        .expect("Could not like post");
+    assert_eq!(liked_post.own_favorite, Some(true));
+    assert_eq!(liked_post.own_score, Some(1));
+    let unliked_post = client
+        .request()
+        .unfavorite_post(folly3_post.id.unwrap())
+        .await
+        .expect("Could not unfavorite liked post");
+    assert_eq!(unliked_post.own_favorite, Some(false));
+
+    info!("Editing post across several commits");
+    let mut editor = client
+        .request()
+        .edit_post(folly3_post.id.unwrap())
+        .await
+        .expect("Could not start editing post");
+    let start_version = editor.version();
+    let edited_post = editor
+        .add_tags(["orange_cat"])
+        .set_source("https://example.com/folly3.jpg")
+        .commit()
+        .await
+        .expect("Could not commit first post edit");
+    assert!(editor.tags().contains(&"orange_cat".to_string()));
+    assert_eq!(edited_post.version, Some(editor.version()));
+    assert!(editor.version() > start_version);
+    let edited_post = editor
+        .remove_tags(["orange_cat"])
+        .commit()
+        .await
+        .expect("Could not commit second post edit");
+    assert!(!editor.tags().contains(&"orange_cat".to_string()));
+    assert_eq!(
+        edited_post.source.as_deref(),
+        Some("https://example.com/folly3.jpg")
+    );
+
     info!("Featuring post");
     let featured_post = client
         .request()
@@ -662,7 +1094,7 @@ async fn test_pools(client: &SzurubooruClient) {
         .collect::<Vec<u32>>();
     let update_pool = CreateUpdatePoolBuilder::default()
         .version(cat_pool.version.unwrap())
-        .posts(post_ids)
+        .posts(post_ids.clone())
         .description("All cat pictures all the time".to_string())
         .build()
         .expect("Could not build update object");
@@ -672,6 +1104,19 @@ async fn test_pools(client: &SzurubooruClient) {
         .await
         .expect("Unable to update pool");
 
+    info!("Streaming pool posts");
+    let pool_posts = client
+        .with_limit(3)
+        .pool_posts_stream(cat_pool.id.unwrap())
+        .try_collect::<Vec<_>>()
+        .await
+        .expect("Could not stream pool posts");
+    let pool_post_ids = pool_posts
+        .into_iter()
+        .map(|p| p.id.unwrap())
+        .collect::<Vec<u32>>();
+    assert_eq!(pool_post_ids, post_ids);
+
     info!("Merging pools");
     let merge_pool_obj = MergePoolBuilder::default()
         .remove_pool_version(catz_pool.version.unwrap())
@@ -685,6 +1130,24 @@ async fn test_pools(client: &SzurubooruClient) {
         .merge_pools(&merge_pool_obj)
         .await
         .expect("Unable to merge pools");
+
+    info!("Merging pools by ID");
+    let create_pool4 = CreateUpdatePoolBuilder::default()
+        .names(vec!["kittens_pool".to_string()])
+        .category("cat_pool_category".to_string())
+        .build()
+        .expect("Could not build pool creation object");
+    let kittens_pool = client
+        .request()
+        .create_pool(&create_pool4)
+        .await
+        .expect("Could not create pool");
+    let merged_pool = client
+        .request()
+        .merge_pools_by_id(kittens_pool.id.unwrap(), cat_pool.id.unwrap())
+        .await
+        .expect("Unable to merge pools by ID");
+    assert_eq!(merged_pool.id, cat_pool.id);
 }
 
 #[instrument(skip(client))]
@@ -752,6 +1215,12 @@ async fn test_comments(client: &SzurubooruClient) {
         .await
         .expect("Unable to fetch comments for post");
     assert_ne!(comment_list.total, 0);
+    let post_comments = client
+        .request()
+        .get_comments_for_post(post_id)
+        .await
+        .expect("Unable to fetch comments with get_comments_for_post");
+    assert_eq!(post_comments.total, comment_list.total);
 
     info!("Rating comment");
     let comment = client
@@ -781,6 +1250,16 @@ async fn test_users(client: &SzurubooruClient) {
         .expect("Could not list users");
     assert_eq!(user_list.total, 1);
 
+    info!("Checking the current user's privileges");
+    let can_delete = client
+        .request()
+        .can_current_user("posts:delete")
+        .await
+        .expect("Could not check privileges");
+    assert!(can_delete);
+    let unknown = client.request().can_current_user("posts:juggle").await;
+    assert!(unknown.is_err());
+
     // Create user is already tested above
     info!("Creating user with avatar");
     let avatar_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../avatar.jpg");
@@ -793,7 +1272,7 @@ async fn test_users(client: &SzurubooruClient) {
         .expect("Could not create user creation object");
     let user_obj = client
         .request()
-        .create_user_with_avatar_path(avatar_path, &create_user)
+        .create_user_with_avatar_path(&avatar_path, &create_user)
         .await
         .expect("Could not create user");
 
@@ -809,6 +1288,19 @@ async fn test_users(client: &SzurubooruClient) {
         .await
         .expect("Could not update user");
 
+    info!("Updating user avatar from bytes");
+    let avatar = std::fs::read(&avatar_path).expect("Could not read avatar");
+    let update_user = CreateUpdateUserBuilder::default()
+        .version(user_obj.version.unwrap())
+        .avatar_style(UserAvatarStyle::Manual)
+        .build()
+        .expect("Could not create user update object");
+    let user_obj = client
+        .request()
+        .update_user_with_avatar_bytes(user_obj.name.unwrap(), avatar, "avatar.jpg", &update_user)
+        .await
+        .expect("Could not update user avatar");
+
     info!("Getting user");
     let user_obj = client
         .request()
@@ -879,6 +1371,40 @@ async fn test_snapshots(client: &SzurubooruClient) {
         .await
         .expect("Could not list snapshots");
     assert!(snap_list.total > 0);
+
+    info!("Listing deletion snapshots");
+    let deletions = client
+        .request()
+        .list_snapshots_for_operation(SnapshotOperationType::Deleted)
+        .await
+        .expect("Could not list deletion snapshots");
+    assert!(deletions.total > 0);
+    assert!(deletions
+        .results
+        .iter()
+        .all(|s| s.operation == Some(SnapshotOperationType::Deleted)));
+
+    info!("Listing the history of the newest snapshot's resource");
+    let newest = &snap_list.results[0];
+    let history = client
+        .request()
+        .list_snapshots_for(
+            newest.resource_type.clone().unwrap(),
+            newest.id.as_deref().unwrap(),
+        )
+        .await
+        .expect("Could not list snapshots for a resource");
+    assert!(history.total > 0);
+    assert!(history.results.iter().all(|s| s.id == newest.id));
+
+    info!("Catching up on snapshots since the second newest one");
+    let newer = client
+        .request()
+        .snapshots_since(&snap_list.results[1], 1000)
+        .await
+        .expect("Could not list snapshots since the second newest");
+    assert_eq!(newer.len(), 1);
+    assert_eq!(newer[0].time, snap_list.results[0].time);
 }
 
 #[instrument(skip(client))]
@@ -931,4 +1457,61 @@ async fn test_downloads(client: &SzurubooruClient) {
     let dl_f3_hash = dl_f3_hasher.finalize();
 
     assert_eq!(f3_hash, dl_f3_hash);
+
+    info!("Testing download from a fetched post");
+    let mut content_file = tempfile().expect("Could not create temporary file");
+    client
+        .request()
+        .download_post_content(&f3post, &mut content_file)
+        .await
+        .expect("Could not download post content");
+    content_file
+        .seek(SeekFrom::Start(0))
+        .expect("Could not rewind file");
+    let mut content_hasher = Sha1::new();
+    std::io::copy(&mut content_file, &mut content_hasher).expect("Unable to hash folly3.jpg");
+    assert_eq!(f3_hash, content_hasher.finalize());
+
+    info!("Testing download with progress");
+    let mut progress_file = tempfile().expect("Could not create temporary file");
+    let mut progress_calls = Vec::new();
+    client
+        .request()
+        .download_image_to_file_with_progress(
+            f3post.id.unwrap(),
+            &mut progress_file,
+            |written, total| progress_calls.push((written, total)),
+        )
+        .await
+        .expect("Could not download with progress");
+    let (written, total) = *progress_calls.last().expect("Expected a progress update");
+    assert_eq!(Some(written), f3post.file_size);
+    assert!(total.is_none_or(|t| t == written));
+    assert!(progress_calls.windows(2).all(|w| w[0].0 < w[1].0));
+
+    info!("Testing raw image download");
+    let raw_bytes = client
+        .request()
+        .get_image_raw_bytes(f3post.id.unwrap())
+        .await
+        .expect("Could not download raw image bytes");
+    assert_eq!(Sha1::digest(&raw_bytes), f3_hash);
+
+    info!("Testing batch download to a directory");
+    let dl_dir = tempfile::tempdir().expect("Could not create temporary directory");
+    let dl_results = client
+        .request()
+        .download_images_to_dir(&[f3post.id.unwrap(), 999_999], dl_dir.path(), 2)
+        .await;
+    assert_eq!(dl_results.len(), 2);
+    let dl_path = dl_results[0]
+        .as_ref()
+        .expect("Could not download folly3 to directory");
+    assert_eq!(
+        dl_path,
+        &dl_dir.path().join(format!("{}.jpg", f3post.id.unwrap()))
+    );
+    let dl_bytes = std::fs::read(dl_path).expect("Could not read downloaded folly3");
+    assert_eq!(Sha1::digest(&dl_bytes), f3_hash);
+    assert!(dl_results[1].is_err());
 }